@@ -0,0 +1,563 @@
+// The static-arena module
+
+use core::{fmt, iter::{Enumerate, FusedIterator}, mem, ops, slice};
+
+use crate::vec::{CapacityError, StaticVec};
+
+/// The checked handle of a value stored in a `StaticArena`. \
+/// It consists of the slot of the value, and the generation of the slot when the value was inserted.
+/// 
+/// Every time a value is removed, the generation of its slot increases.
+/// Therefore, a handle of a removed value never refers to a value inserted later into the same slot.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Index
+{
+	slot:usize,
+	generation:u64
+}
+
+impl Index
+{
+	/// Returns the slot of this handle.
+	pub const fn slot(self)->usize
+	{
+		self.slot
+	}
+
+	/// Returns the generation of this handle.
+	pub const fn generation(self)->u64
+	{
+		self.generation
+	}
+}
+
+#[derive(Clone)]
+enum Slot<T>
+{
+	Occupied{generation:u64,value:T},
+	// A vacant slot holds the index of the next vacant slot, and the generation of the next value in this slot.
+	Vacant{generation:u64,next:usize}
+}
+
+/// The `StaticArena` type is a fixed-capacity generational arena which can hold `N` values at most. \
+/// Values are referred by `Index` handles. Unlike `StaticSlab`, a stale handle is detected,
+/// even if its slot is reused by another value.
+/// 
+/// # Example
+/// ```
+/// use static_collections::arena::StaticArena;
+/// let mut a:StaticArena<4,&str>=StaticArena::new();
+/// let x=a.insert("x").unwrap();
+/// assert_eq!(a.remove(x),Some("x"));
+/// let y=a.insert("y").unwrap();
+/// assert_eq!(x.slot(),y.slot());
+/// assert_eq!(a.get(x),None);
+/// assert_eq!(a.get(y),Some(&"y"));
+/// ```
+pub struct StaticArena<const N:usize,T>
+{
+	slots:StaticVec<N,Slot<T>>,
+	// Head of the free list. If it equals to `slots.len()`, there is no vacant slot in the list.
+	next:usize,
+	length:usize
+}
+
+impl<const N:usize,T> Default for StaticArena<N,T>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,T> StaticArena<N,T>
+{
+	/// Constructs a new, empty `StaticArena`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::arena::StaticArena;
+	/// let a:StaticArena<8,u64>=StaticArena::new();
+	/// assert!(a.is_empty());
+	/// ```
+	pub const fn new()->Self
+	{
+		Self
+		{
+			slots:StaticVec::new(),
+			next:0,
+			length:0
+		}
+	}
+
+	/// Returns the number of values in the static-arena.
+	pub const fn len(&self)->usize
+	{
+		self.length
+	}
+
+	/// Checks if the static-arena is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.length==0
+	}
+
+	/// Checks if the static-arena is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.length==N
+	}
+
+	/// Returns the number of values the static-arena can hold.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Inserts a value into the static-arena, and returns the handle of the value. \
+	/// Returns `None` if the static-arena is full. In this case, the value is dropped. \
+	/// Use `try_insert` method if you would like to take the value back.
+	pub fn insert(&mut self,value:T)->Option<Index>
+	{
+		self.try_insert(value).ok()
+	}
+
+	/// Inserts a value into the static-arena, and returns the handle of the value. \
+	/// Returns `Err(CapacityError<T>)` with the value if the static-arena is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::arena::StaticArena;
+	/// let mut a:StaticArena<1,u64>=StaticArena::new();
+	/// assert!(a.try_insert(1).is_ok());
+	/// assert_eq!(a.try_insert(2).unwrap_err().into_inner(),2);
+	/// ```
+	pub fn try_insert(&mut self,value:T)->Result<Index,CapacityError<T>>
+	{
+		let slot=self.next;
+		let generation;
+		if slot==self.slots.len()
+		{
+			generation=0;
+			if let Err(e)=self.slots.try_push(Slot::Occupied{generation,value})
+			{
+				let Slot::Occupied{value,..}=e.into_inner() else {unreachable!()};
+				return Err(CapacityError::new(value));
+			}
+			self.next+=1;
+		}
+		else
+		{
+			match self.slots[slot]
+			{
+				Slot::Vacant{generation:g,next}=>
+				{
+					generation=g;
+					self.next=next;
+				}
+				Slot::Occupied{..}=>unreachable!("The free list of StaticArena is corrupted!")
+			}
+			self.slots[slot]=Slot::Occupied{generation,value};
+		}
+		self.length+=1;
+		Ok(Index{slot,generation})
+	}
+
+	// Vacates an occupied slot, and returns the value in it.
+	fn vacate(&mut self,slot:usize)->T
+	{
+		let generation=match self.slots[slot]
+		{
+			Slot::Occupied{generation,..}=>generation,
+			Slot::Vacant{..}=>unreachable!()
+		};
+		let Slot::Occupied{value,..}=mem::replace(&mut self.slots[slot],Slot::Vacant{generation:generation.wrapping_add(1),next:self.next}) else {unreachable!()};
+		self.next=slot;
+		self.length-=1;
+		value
+	}
+
+	/// Removes the value of the `index` from the static-arena, and returns it. \
+	/// Returns `None` if the `index` is stale or does not refer to any value.
+	pub fn remove(&mut self,index:Index)->Option<T>
+	{
+		if self.contains(index)
+		{
+			Some(self.vacate(index.slot))
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Checks if the `index` refers to a value in the static-arena.
+	pub fn contains(&self,index:Index)->bool
+	{
+		self.get(index).is_some()
+	}
+
+	/// Returns a reference to the value of the `index`. \
+	/// Returns `None` if the `index` is stale or does not refer to any value.
+	pub fn get(&self,index:Index)->Option<&T>
+	{
+		match self.slots.get(index.slot)
+		{
+			Some(Slot::Occupied{generation,value}) if *generation==index.generation=>Some(value),
+			_=>None
+		}
+	}
+
+	/// Returns a mutable reference to the value of the `index`. \
+	/// Returns `None` if the `index` is stale or does not refer to any value.
+	pub fn get_mut(&mut self,index:Index)->Option<&mut T>
+	{
+		match self.slots.get_mut(index.slot)
+		{
+			Some(Slot::Occupied{generation,value}) if *generation==index.generation=>Some(value),
+			_=>None
+		}
+	}
+
+	/// Returns an iterator of the handles and values in the static-arena, in ascending order of slots.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::arena::StaticArena;
+	/// let mut a:StaticArena<8,char>=StaticArena::new();
+	/// let x=a.insert('x').unwrap();
+	/// let y=a.insert('y').unwrap();
+	/// assert!(a.iter().eq([(x,&'x'),(y,&'y')]));
+	/// ```
+	pub fn iter(&self)->Iter<'_,T>
+	{
+		Iter
+		{
+			slots:self.slots.iter().enumerate(),
+			remaining:self.length
+		}
+	}
+
+	/// Returns an iterator of the handles and mutable references to values in the static-arena, in ascending order of slots.
+	pub fn iter_mut(&mut self)->IterMut<'_,T>
+	{
+		IterMut
+		{
+			slots:self.slots.iter_mut().enumerate(),
+			remaining:self.length
+		}
+	}
+
+	/// Retains only the values specified by the predicate. \
+	/// In other words, remove all values `v` with handle `i` such that `f(i,&mut v)` returns `false`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::arena::StaticArena;
+	/// let mut a:StaticArena<8,u32>=StaticArena::new();
+	/// let x=a.insert(1).unwrap();
+	/// let y=a.insert(2).unwrap();
+	/// a.retain(|_,v| *v%2==0);
+	/// assert_eq!(a.get(x),None);
+	/// assert_eq!(a.get(y),Some(&2));
+	/// ```
+	pub fn retain<F:FnMut(Index,&mut T)->bool>(&mut self,mut f:F)
+	{
+		for slot in 0..self.slots.len()
+		{
+			if let Slot::Occupied{generation,value}=&mut self.slots[slot] && !f(Index{slot,generation:*generation},value)
+			{
+				self.vacate(slot);
+			}
+		}
+	}
+
+	/// Removes all values from the static-arena and returns them with their handles as an iterator. \
+	/// When the iterator is dropped, all remaining values are removed. All handles become stale.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::arena::StaticArena;
+	/// let mut a:StaticArena<8,u32>=StaticArena::new();
+	/// let x=a.insert(1).unwrap();
+	/// assert!(a.drain().eq([(x,1)]));
+	/// assert!(a.is_empty());
+	/// let y=a.insert(2).unwrap();
+	/// assert_eq!(a.get(x),None);
+	/// ```
+	pub fn drain(&mut self)->Drain<'_,N,T>
+	{
+		Drain
+		{
+			arena:self,
+			slot:0
+		}
+	}
+
+	/// Removes all values from the static-arena. All handles become stale.
+	pub fn clear(&mut self)
+	{
+		self.drain();
+	}
+}
+
+impl<const N:usize,T:Clone> Clone for StaticArena<N,T>
+{
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			slots:self.slots.iter().cloned().collect(),
+			next:self.next,
+			length:self.length
+		}
+	}
+}
+
+impl<const N:usize,T:fmt::Debug> fmt::Debug for StaticArena<N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<const N:usize,T> ops::Index<Index> for StaticArena<N,T>
+{
+	type Output = T;
+
+	fn index(&self, index: Index) -> &Self::Output
+	{
+		match self.get(index)
+		{
+			Some(v)=>v,
+			None=>panic!("Invalid StaticArena index {index:?}!")
+		}
+	}
+}
+
+impl<const N:usize,T> ops::IndexMut<Index> for StaticArena<N,T>
+{
+	fn index_mut(&mut self, index: Index) -> &mut Self::Output
+	{
+		match self.get_mut(index)
+		{
+			Some(v)=>v,
+			None=>panic!("Invalid StaticArena index {index:?}!")
+		}
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a StaticArena<N,T>
+{
+	type Item = (Index,&'a T);
+	type IntoIter = Iter<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a mut StaticArena<N,T>
+{
+	type Item = (Index,&'a mut T);
+	type IntoIter = IterMut<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+/// Immutable static-arena iterator.
+pub struct Iter<'a,T>
+{
+	slots:Enumerate<slice::Iter<'a,Slot<T>>>,
+	remaining:usize
+}
+
+impl<'a,T> Iterator for Iter<'a,T>
+{
+	type Item = (Index,&'a T);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let r=self.slots.find_map(|(slot,s)| match s
+		{
+			Slot::Occupied{generation,value}=>Some((Index{slot,generation:*generation},value)),
+			Slot::Vacant{..}=>None
+		})?;
+		self.remaining-=1;
+		Some(r)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,T> ExactSizeIterator for Iter<'a,T> {}
+impl<'a,T> FusedIterator for Iter<'a,T> {}
+
+/// Mutable static-arena iterator.
+pub struct IterMut<'a,T>
+{
+	slots:Enumerate<slice::IterMut<'a,Slot<T>>>,
+	remaining:usize
+}
+
+impl<'a,T> Iterator for IterMut<'a,T>
+{
+	type Item = (Index,&'a mut T);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let r=self.slots.find_map(|(slot,s)| match s
+		{
+			Slot::Occupied{generation,value}=>Some((Index{slot,generation:*generation},value)),
+			Slot::Vacant{..}=>None
+		})?;
+		self.remaining-=1;
+		Some(r)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,T> ExactSizeIterator for IterMut<'a,T> {}
+impl<'a,T> FusedIterator for IterMut<'a,T> {}
+
+/// A draining iterator of a static-arena. \
+/// This struct is created by the `drain` method of `StaticArena`.
+pub struct Drain<'a,const N:usize,T>
+{
+	arena:&'a mut StaticArena<N,T>,
+	slot:usize
+}
+
+impl<'a,const N:usize,T> Iterator for Drain<'a,N,T>
+{
+	type Item = (Index,T);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		while self.slot<self.arena.slots.len()
+		{
+			let slot=self.slot;
+			self.slot+=1;
+			if let Slot::Occupied{generation,..}=self.arena.slots[slot]
+			{
+				return Some((Index{slot,generation},self.arena.vacate(slot)));
+			}
+		}
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.arena.length,Some(self.arena.length))
+	}
+}
+
+impl<'a,const N:usize,T> ExactSizeIterator for Drain<'a,N,T> {}
+impl<'a,const N:usize,T> FusedIterator for Drain<'a,N,T> {}
+
+impl<'a,const N:usize,T> Drop for Drain<'a,N,T>
+{
+	fn drop(&mut self)
+	{
+		self.for_each(drop);
+	}
+}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::vec::Vec;
+	use crate::{arena::{Index, StaticArena}, xorshift};
+
+	#[test] fn stale_index()
+	{
+		let mut state:u64=0x0F1E_2D3C_4B5A_6978;
+		let mut a:StaticArena<16,u64>=StaticArena::new();
+		let mut live:Vec<(Index,u64)>=Vec::new();
+		let mut dead:Vec<Index>=Vec::new();
+		for _ in 0..10000
+		{
+			let r=xorshift(&mut state);
+			match r%5
+			{
+				0..=2=>
+				{
+					if let Some(i)=a.insert(r)
+					{
+						live.push((i,r));
+					}
+					else
+					{
+						assert_eq!(live.len(),16);
+					}
+				}
+				3=>
+				{
+					if !live.is_empty()
+					{
+						let (i,v)=live.swap_remove((r>>8) as usize%live.len());
+						assert_eq!(a.remove(i),Some(v));
+						dead.push(i);
+					}
+				}
+				_=>
+				{
+					let p=r>>60;
+					a.retain(|_,v| *v>>60!=p);
+					live.retain(|(i,v)| if *v>>60!=p {true} else {dead.push(*i); false});
+				}
+			}
+			assert_eq!(a.len(),live.len());
+			for (i,v) in live.iter()
+			{
+				assert_eq!(a.get(*i),Some(v));
+			}
+			for i in dead.iter()
+			{
+				assert_eq!(a.get(*i),None);
+			}
+		}
+		let drained:Vec<Index>=a.drain().map(|(i,_)| i).collect();
+		assert_eq!(drained.len(),live.len());
+		assert!(drained.iter().all(|i| a.get(*i).is_none()));
+	}
+
+	#[test] fn reused_slot()
+	{
+		let mut a:StaticArena<2,&str>=StaticArena::new();
+		let x=a.insert("x").unwrap();
+		let y=a.insert("y").unwrap();
+		assert_eq!(a.try_insert("z").unwrap_err().into_inner(),"z");
+		assert_eq!(a.remove(x),Some("x"));
+		assert_eq!(a.remove(x),None);
+		// The stale handle differs from the new handle in generation only.
+		let z=a.insert("z").unwrap();
+		assert_eq!(z.slot(),x.slot());
+		assert_eq!(z.generation(),x.generation()+1);
+		assert!(!a.contains(x));
+		assert_eq!(a.get_mut(x),None);
+		assert_eq!(a.remove(x),None);
+		assert_eq!(a[z],"z");
+		// Clearing makes every handle stale, including those of reused slots.
+		a.clear();
+		assert!(a.is_empty());
+		assert!(!a.contains(y) && !a.contains(z));
+		let w=a.insert("w").unwrap();
+		assert!(w!=y && w!=z);
+		assert_eq!(a.get(y),None);
+		assert_eq!(a.get(z),None);
+	}
+}
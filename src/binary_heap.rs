@@ -0,0 +1,504 @@
+// The static-binary-heap module
+
+use core::{fmt, iter::FusedIterator, ops::{Deref, DerefMut}, slice};
+
+use crate::vec::{self, CapacityError, StaticVec};
+
+/// The `StaticBinaryHeap` type is a fixed-capacity priority queue implemented with a binary heap. \
+/// It can hold `N` elements at most, and it is a max-heap: `pop` returns the greatest element.
+/// 
+/// Wrap elements with `core::cmp::Reverse` to use it as a min-heap.
+/// 
+/// # Example
+/// ```
+/// use core::cmp::Reverse;
+/// use static_collections::binary_heap::StaticBinaryHeap;
+/// let mut h:StaticBinaryHeap<8,Reverse<u32>>=StaticBinaryHeap::new();
+/// h.push(Reverse(5));
+/// h.push(Reverse(1));
+/// h.push(Reverse(3));
+/// assert_eq!(h.pop(),Some(Reverse(1)));
+/// assert_eq!(h.peek(),Some(&Reverse(3)));
+/// ```
+pub struct StaticBinaryHeap<const N:usize,T>
+{
+	data:StaticVec<N,T>
+}
+
+impl<const N:usize,T> Default for StaticBinaryHeap<N,T>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,T> StaticBinaryHeap<N,T>
+{
+	/// Constructs a new, empty `StaticBinaryHeap`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::binary_heap::StaticBinaryHeap;
+	/// let h:StaticBinaryHeap<8,u32>=StaticBinaryHeap::new();
+	/// assert!(h.is_empty());
+	/// ```
+	pub const fn new()->Self
+	{
+		Self
+		{
+			data:StaticVec::new()
+		}
+	}
+
+	/// Returns the number of elements in the static-binary-heap.
+	pub const fn len(&self)->usize
+	{
+		self.data.len()
+	}
+
+	/// Checks if the static-binary-heap is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.data.len()==0
+	}
+
+	/// Checks if the static-binary-heap is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.data.len()==N
+	}
+
+	/// Returns the number of elements the static-binary-heap can hold.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Returns the greatest element in the static-binary-heap, or `None` if it is empty.
+	pub fn peek(&self)->Option<&T>
+	{
+		self.data.first()
+	}
+
+	/// Returns all elements in the underlying storage as a slice, in arbitrary order.
+	pub const fn as_slice(&self)->&[T]
+	{
+		self.data.as_slice()
+	}
+
+	/// Returns an iterator of all elements in the underlying storage, in arbitrary order.
+	pub fn iter(&self)->Iter<'_,T>
+	{
+		Iter{inner:self.data.iter()}
+	}
+
+	/// Consumes the static-binary-heap and returns the underlying static vector, in arbitrary order.
+	pub fn into_vec(self)->StaticVec<N,T>
+	{
+		self.data
+	}
+
+	/// Removes all elements from the static-binary-heap and returns them as an iterator, in arbitrary order.
+	pub fn drain(&mut self)->vec::Drain<'_,N,T>
+	{
+		self.data.drain(..)
+	}
+
+	/// Removes all elements from the static-binary-heap.
+	pub fn clear(&mut self)
+	{
+		self.data.clear();
+	}
+}
+
+impl<const N:usize,T:Ord> StaticBinaryHeap<N,T>
+{
+	/// Converts a static vector into a static-binary-heap in `O(n)` time.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{binary_heap::StaticBinaryHeap,vec::StaticVec};
+	/// let v:StaticVec<8,u32>=[3,1,4,1,5,9,2,6].into_iter().collect();
+	/// let h=StaticBinaryHeap::from_vec(v);
+	/// assert_eq!(h.peek(),Some(&9));
+	/// ```
+	pub fn from_vec(vec:StaticVec<N,T>)->Self
+	{
+		let mut h=Self{data:vec};
+		let len=h.data.len();
+		for i in (0..len/2).rev()
+		{
+			h.sift_down_range(i,len);
+		}
+		h
+	}
+
+	// Moves the element at `pos` up until its parent is not less than it.
+	fn sift_up(&mut self,mut pos:usize)
+	{
+		while pos>0
+		{
+			let parent=(pos-1)/2;
+			if self.data[pos]<=self.data[parent]
+			{
+				break;
+			}
+			self.data.swap(pos,parent);
+			pos=parent;
+		}
+	}
+
+	// Moves the element at `pos` down until none of its children within `..end` is greater than it.
+	fn sift_down_range(&mut self,mut pos:usize,end:usize)
+	{
+		loop
+		{
+			let mut child=2*pos+1;
+			if child>=end
+			{
+				break;
+			}
+			if child+1<end && self.data[child]<self.data[child+1]
+			{
+				child+=1;
+			}
+			if self.data[pos]>=self.data[child]
+			{
+				break;
+			}
+			self.data.swap(pos,child);
+			pos=child;
+		}
+	}
+
+	/// Pushes an element into the static-binary-heap.
+	/// 
+	/// # Panic
+	/// If the static-binary-heap is full, this method would panic. \
+	/// Use `try_push` method if you would like to handle the overflow.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::binary_heap::StaticBinaryHeap;
+	/// let mut h:StaticBinaryHeap<8,u32>=StaticBinaryHeap::new();
+	/// h.push(3);
+	/// h.push(5);
+	/// assert_eq!(h.peek(),Some(&5));
+	/// ```
+	pub fn push(&mut self,v:T)
+	{
+		if self.try_push(v).is_err()
+		{
+			panic!("StaticBinaryHeap buffer overflow!");
+		}
+	}
+
+	/// Pushes an element into the static-binary-heap. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static-binary-heap is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::binary_heap::StaticBinaryHeap;
+	/// let mut h:StaticBinaryHeap<1,u32>=StaticBinaryHeap::new();
+	/// assert!(h.try_push(3).is_ok());
+	/// assert_eq!(h.try_push(5).unwrap_err().into_inner(),5);
+	/// ```
+	pub fn try_push(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		self.data.try_push(v)?;
+		self.sift_up(self.data.len()-1);
+		Ok(())
+	}
+
+	/// Removes the greatest element from the static-binary-heap and returns it, or `None` if it is empty.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::binary_heap::StaticBinaryHeap;
+	/// let mut h:StaticBinaryHeap<8,u32>=[1,3,2].into_iter().collect();
+	/// assert_eq!(h.pop(),Some(3));
+	/// assert_eq!(h.pop(),Some(2));
+	/// assert_eq!(h.pop(),Some(1));
+	/// assert_eq!(h.pop(),None);
+	/// ```
+	pub fn pop(&mut self)->Option<T>
+	{
+		let len=self.data.len();
+		if len>1
+		{
+			self.data.swap(0,len-1);
+		}
+		let v=self.data.pop()?;
+		self.sift_down_range(0,self.data.len());
+		Some(v)
+	}
+
+	/// Returns a mutable reference to the greatest element in the static-binary-heap, or `None` if it is empty. \
+	/// The heap is restored when the returned `PeekMut` is dropped.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::binary_heap::StaticBinaryHeap;
+	/// let mut h:StaticBinaryHeap<8,u32>=[1,5,2].into_iter().collect();
+	/// *h.peek_mut().unwrap()=0;
+	/// assert_eq!(h.peek(),Some(&2));
+	/// ```
+	pub fn peek_mut(&mut self)->Option<PeekMut<'_,N,T>>
+	{
+		if self.is_empty()
+		{
+			None
+		}
+		else
+		{
+			Some(PeekMut{heap:self})
+		}
+	}
+
+	/// Consumes the static-binary-heap and returns a static vector sorted in ascending order.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::binary_heap::StaticBinaryHeap;
+	/// let h:StaticBinaryHeap<8,u32>=[3,1,4,1,5].into_iter().collect();
+	/// assert_eq!(h.into_sorted_vec().as_slice(),&[1,1,3,4,5]);
+	/// ```
+	pub fn into_sorted_vec(mut self)->StaticVec<N,T>
+	{
+		let mut end=self.data.len();
+		while end>1
+		{
+			end-=1;
+			self.data.swap(0,end);
+			self.sift_down_range(0,end);
+		}
+		self.data
+	}
+
+	/// Retains only the elements specified by the predicate. \
+	/// In other words, remove all elements `e` such that `f(&e)` returns `false`.
+	pub fn retain<F:FnMut(&T)->bool>(&mut self,f:F)
+	{
+		let len=self.data.len();
+		self.data.retain(f);
+		if self.data.len()<len
+		{
+			*self=Self::from_vec(core::mem::take(&mut self.data));
+		}
+	}
+}
+
+impl<const N:usize,T:Clone> Clone for StaticBinaryHeap<N,T>
+{
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			data:self.data.iter().cloned().collect()
+		}
+	}
+}
+
+impl<const N:usize,T:fmt::Debug> fmt::Debug for StaticBinaryHeap<N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<const N:usize,T:Ord> From<StaticVec<N,T>> for StaticBinaryHeap<N,T>
+{
+	fn from(value: StaticVec<N,T>) -> Self
+	{
+		Self::from_vec(value)
+	}
+}
+
+impl<const N:usize,T> From<StaticBinaryHeap<N,T>> for StaticVec<N,T>
+{
+	fn from(value: StaticBinaryHeap<N,T>) -> Self
+	{
+		value.into_vec()
+	}
+}
+
+impl<const N:usize,T:Ord> Extend<T> for StaticBinaryHeap<N,T>
+{
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+	{
+		for v in iter
+		{
+			self.push(v);
+		}
+	}
+}
+
+impl<const N:usize,T:Ord> FromIterator<T> for StaticBinaryHeap<N,T>
+{
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self
+	{
+		Self::from_vec(StaticVec::from_iter(iter))
+	}
+}
+
+impl<const N:usize,T> IntoIterator for StaticBinaryHeap<N,T>
+{
+	type Item = T;
+	type IntoIter = vec::IntoIter<N,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.data.into_iter()
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a StaticBinaryHeap<N,T>
+{
+	type Item = &'a T;
+	type IntoIter = Iter<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+/// A mutable reference to the greatest element of a static-binary-heap. \
+/// This struct is created by the `peek_mut` method of `StaticBinaryHeap`.
+/// When it is dropped, the element is moved down to restore the heap.
+pub struct PeekMut<'a,const N:usize,T:Ord>
+{
+	heap:&'a mut StaticBinaryHeap<N,T>
+}
+
+impl<'a,const N:usize,T:Ord> PeekMut<'a,N,T>
+{
+	/// Removes the peeked element from the static-binary-heap and returns it.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::binary_heap::{StaticBinaryHeap,PeekMut};
+	/// let mut h:StaticBinaryHeap<8,u32>=[1,5,2].into_iter().collect();
+	/// let top=h.peek_mut().unwrap();
+	/// assert_eq!(PeekMut::pop(top),5);
+	/// assert_eq!(h.len(),2);
+	/// ```
+	pub fn pop(this:Self)->T
+	{
+		let v=this.heap.pop().unwrap();
+		// The heap has been restored by `pop`.
+		core::mem::forget(this);
+		v
+	}
+}
+
+impl<'a,const N:usize,T:Ord> Deref for PeekMut<'a,N,T>
+{
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target
+	{
+		&self.heap.data[0]
+	}
+}
+
+impl<'a,const N:usize,T:Ord> DerefMut for PeekMut<'a,N,T>
+{
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		&mut self.heap.data[0]
+	}
+}
+
+impl<'a,const N:usize,T:Ord> Drop for PeekMut<'a,N,T>
+{
+	fn drop(&mut self)
+	{
+		let len=self.heap.len();
+		self.heap.sift_down_range(0,len);
+	}
+}
+
+/// Immutable static-binary-heap iterator, in arbitrary order.
+pub struct Iter<'a,T>
+{
+	inner:slice::Iter<'a,T>
+}
+
+impl<'a,T> Iterator for Iter<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,T> DoubleEndedIterator for Iter<'a,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back()
+	}
+}
+
+impl<'a,T> ExactSizeIterator for Iter<'a,T> {}
+impl<'a,T> FusedIterator for Iter<'a,T> {}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::vec::Vec;
+	use crate::{binary_heap::StaticBinaryHeap, xorshift};
+
+	#[test] fn heap_order()
+	{
+		let mut state:u64=0xDEAD_BEEF_CAFE_F00D;
+		let mut h:StaticBinaryHeap<64,u32>=StaticBinaryHeap::new();
+		let mut model:Vec<u32>=Vec::new();
+		for _ in 0..5000
+		{
+			let r=xorshift(&mut state);
+			let v=(r%100) as u32;
+			match (r>>32)%4
+			{
+				0|1=>
+				{
+					let r=h.try_push(v);
+					assert_eq!(r.is_ok(),model.len()<64);
+					if r.is_ok()
+					{
+						model.push(v);
+					}
+				}
+				2=>
+				{
+					model.sort();
+					assert_eq!(h.pop(),model.pop());
+				}
+				_=>
+				{
+					if let Some(mut top)=h.peek_mut()
+					{
+						model.sort();
+						assert_eq!(Some(&*top),model.last());
+						*top=v;
+						*model.last_mut().unwrap()=v;
+					}
+				}
+			}
+			assert_eq!(h.len(),model.len());
+		}
+		model.sort();
+		assert_eq!(h.into_sorted_vec().as_slice(),model.as_slice());
+	}
+}
@@ -1,10 +1,74 @@
 // The bitmap module
-use core::fmt;
+use core::{fmt, iter::FusedIterator, ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Deref, DerefMut, Not, Range, RangeBounds}, sync::atomic::{AtomicUsize, Ordering}};
 #[cfg(target_arch="x86_64")]
 use core::arch::{asm, x86_64::{_bittest64,_bittestandcomplement64,_bittestandreset64,_bittestandset64}};
 #[cfg(target_arch="x86")]
 use core::arch::{asm, x86::{_bittest,_bittestandcomplement,_bittestandreset,_bittestandset}};
 
+// The word type used by word-at-a-time operations.
+// It must be consistent with the bit instructions used by single-bit operations.
+#[cfg(target_arch="x86_64")]
+type Word=u64;
+#[cfg(not(target_arch="x86_64"))]
+type Word=u32;
+const WORD_BITS:usize=Word::BITS as usize;
+
+// Finds the lowest set bit in `v`. Returns `None` if `v` is zero.
+#[inline(always)] fn bit_scan_forward(v:Word)->Option<usize>
+{
+	#[cfg(any(target_arch="x86_64",target_arch="x86"))]
+	{
+		let j:Word;
+		let b:u8;
+		unsafe
+		{
+			asm!
+			(
+				"bsf {r},{v}",
+				"setz {zf}",
+				v=in(reg) v,
+				r=out(reg) j,
+				zf=out(reg_byte) b,
+				options(pure,nomem,nostack)
+			);
+		}
+		if b==0 {Some(j as usize)} else {None}
+	}
+	#[cfg(all(not(target_arch="x86_64"),not(target_arch="x86")))]
+	{
+		// Unknown CPU architecture. Use the generic method.
+		if v==0 {None} else {Some(v.trailing_zeros() as usize)}
+	}
+}
+
+// Finds the highest set bit in `v`. Returns `None` if `v` is zero.
+#[inline(always)] fn bit_scan_reverse(v:Word)->Option<usize>
+{
+	#[cfg(any(target_arch="x86_64",target_arch="x86"))]
+	{
+		let j:Word;
+		let b:u8;
+		unsafe
+		{
+			asm!
+			(
+				"bsr {r},{v}",
+				"setz {zf}",
+				v=in(reg) v,
+				r=out(reg) j,
+				zf=out(reg_byte) b,
+				options(pure,nomem,nostack)
+			);
+		}
+		if b==0 {Some(j as usize)} else {None}
+	}
+	#[cfg(all(not(target_arch="x86_64"),not(target_arch="x86")))]
+	{
+		// Unknown CPU architecture. Use the generic method.
+		if v==0 {None} else {Some((WORD_BITS-1)-v.leading_zeros() as usize)}
+	}
+}
+
 #[derive(PartialEq, Debug)]
 pub struct OutOfBitmapError
 {
@@ -32,6 +96,167 @@ impl fmt::Display for OutOfBitmapError
 	}
 }
 
+/// Returns the number of `usize` words required to store a bitmap with `bits` bits.
+/// 
+/// # Example
+/// ```
+/// use static_collections::bitmap::bitmap_words;
+/// assert_eq!(bitmap_words(0),0);
+/// assert_eq!(bitmap_words(1),1);
+/// assert_eq!(bitmap_words(usize::BITS as usize+1),2);
+/// ```
+pub const fn bitmap_words(bits:usize)->usize
+{
+	bits.div_ceil(usize::BITS as usize)
+}
+
+/// The `StaticBitmap` is an owned bitmap with `N` bits, which stores its own `W` words. \
+/// The `W` must be equal to `bitmap_words(N)`, otherwise the compilation would fail.
+/// Use the `static_bitmap!` macro to name this type without computing `W` by hand.
+/// 
+/// The `StaticBitmap` dereferences to `RefBitmap<N>`, so all operations of `RefBitmap` can be used without unsafe codes. \
+/// Bitmaps with equal `N` can be combined with `&`, `|`, `^` and `!` operators.
+/// 
+/// # Example
+/// ```
+/// use static_collections::{static_bitmap,bitmap::StaticBitmap};
+/// let mut bmp:static_bitmap!(300)=StaticBitmap::new();
+/// assert_eq!(bmp.set(299),Ok(false));
+/// assert_eq!(bmp.test(299),Ok(true));
+/// assert_eq!(bmp.search_set_forward(),Some(299));
+/// let inv=!&bmp;
+/// assert_eq!(inv.count_ones(),299);
+/// assert_eq!((&inv|&bmp).count_zeros(),0);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StaticBitmap<const N:usize,const W:usize>
+{
+	words:[usize;W]
+}
+
+impl<const N:usize,const W:usize> StaticBitmap<N,W>
+{
+	const WORDS_CHECK:()=assert!(W==bitmap_words(N),"The number of words does not match the number of bits!");
+
+	/// Creates a new `StaticBitmap` with all bits cleared.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::StaticBitmap;
+	/// static BMP:StaticBitmap<128,{128/usize::BITS as usize}>=StaticBitmap::new();
+	/// assert_eq!(BMP.search_set_forward(),None);
+	/// ```
+	/// Mismatched number of words is rejected at compile time:
+	/// ```compile_fail
+	/// use static_collections::bitmap::StaticBitmap;
+	/// let bmp:StaticBitmap<300,1>=StaticBitmap::new();
+	/// ```
+	pub const fn new()->Self
+	{
+		let _:()=Self::WORDS_CHECK;
+		Self
+		{
+			words:[0;W]
+		}
+	}
+
+	/// Creates a `StaticBitmap` from raw words. \
+	/// Bits beyond `N` in the final word are cleared.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{static_bitmap,bitmap::StaticBitmap};
+	/// let bmp:static_bitmap!(4)=StaticBitmap::from_words([0xFF]);
+	/// assert_eq!(bmp.as_words(),&[0xF]);
+	/// assert_eq!(bmp.test(3),Ok(true));
+	/// ```
+	pub const fn from_words(words:[usize;W])->Self
+	{
+		let _:()=Self::WORDS_CHECK;
+		let mut s=Self{words};
+		let r=N%(usize::BITS as usize);
+		if r!=0
+		{
+			s.words[W-1]&=(1<<r)-1;
+		}
+		s
+	}
+
+	/// Returns the raw words of this bitmap.
+	pub const fn as_words(&self)->&[usize;W]
+	{
+		&self.words
+	}
+
+	/// Consumes this bitmap and returns the raw words.
+	pub const fn into_words(self)->[usize;W]
+	{
+		self.words
+	}
+
+	/// Returns a `RefBitmap` reference to this bitmap.
+	pub const fn as_ref_bitmap(&self)->&RefBitmap<N>
+	{
+		unsafe
+		{
+			&*self.words.as_ptr().cast()
+		}
+	}
+
+	/// Returns a mutable `RefBitmap` reference to this bitmap.
+	pub const fn as_mut_ref_bitmap(&mut self)->&mut RefBitmap<N>
+	{
+		unsafe
+		{
+			&mut *self.words.as_mut_ptr().cast()
+		}
+	}
+}
+
+impl<const N:usize,const W:usize> Default for StaticBitmap<N,W>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,const W:usize> Deref for StaticBitmap<N,W>
+{
+	type Target = RefBitmap<N>;
+
+	fn deref(&self) -> &Self::Target
+	{
+		self.as_ref_bitmap()
+	}
+}
+
+impl<const N:usize,const W:usize> DerefMut for StaticBitmap<N,W>
+{
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		self.as_mut_ref_bitmap()
+	}
+}
+
+/// The `static_bitmap!` macro names the `StaticBitmap` type with specified number of bits,
+/// so that you do not have to compute the number of words by hand.
+/// 
+/// # Example
+/// ```
+/// use static_collections::{static_bitmap,bitmap::StaticBitmap};
+/// type FrameBitmap=static_bitmap!(4096);
+/// let bmp:FrameBitmap=StaticBitmap::new();
+/// assert_eq!(bmp.as_words().len(),4096/usize::BITS as usize);
+/// ```
+#[macro_export] macro_rules! static_bitmap
+{
+	($bits:expr)=>
+	(
+		$crate::bitmap::StaticBitmap<{$bits},{$crate::bitmap::bitmap_words($bits)}>
+	);
+}
+
 /// The ZST `RefBitmap` reference with `N` bits.
 /// 
 /// To create a ref-bitmap, use `from_raw_ptr` and `from_raw_mut_ptr`.
@@ -691,4 +916,1329 @@ impl<const N:usize> RefBitmap<N>
 			None
 		}
 	}
-}
\ No newline at end of file
+
+	// Creates a mask with bits `lo..hi` set. It is required that `lo<hi<=WORD_BITS`.
+	const fn mask(lo:usize,hi:usize)->Word
+	{
+		(Word::MAX>>(WORD_BITS-(hi-lo)))<<lo
+	}
+
+	#[inline(always)] fn read_word(&self,index:usize)->Word
+	{
+		let bmp:*const Word=(&raw const *self).cast();
+		unsafe
+		{
+			bmp.add(index).read_unaligned()
+		}
+	}
+
+	#[inline(always)] fn write_word(&mut self,index:usize,value:Word)
+	{
+		let bmp:*mut Word=(&raw mut *self).cast();
+		unsafe
+		{
+			bmp.add(index).write_unaligned(value)
+		}
+	}
+
+	// Converts a range into a `Range<usize>` bounded by `N`.
+	fn bit_range<R:RangeBounds<usize>>(range:R)->Result<Range<usize>,OutOfBitmapError>
+	{
+		let start=match range.start_bound()
+		{
+			Bound::Included(&s)=>s,
+			Bound::Excluded(&s)=>s.saturating_add(1),
+			Bound::Unbounded=>0
+		};
+		let end=match range.end_bound()
+		{
+			Bound::Included(&e)=>e.saturating_add(1),
+			Bound::Excluded(&e)=>e,
+			Bound::Unbounded=>N
+		};
+		if end>N
+		{
+			Err(OutOfBitmapError::new(end-1,N))
+		}
+		else if start>end
+		{
+			panic!("Range starts at {start} but ends at {end}!");
+		}
+		else
+		{
+			Ok(start..end)
+		}
+	}
+
+	// Calls `f(index,mask)` for every word touched by `range`. The `mask` selects the bits of the word within the range.
+	#[inline(always)] fn for_each_word<F:FnMut(usize,Word)->bool>(range:Range<usize>,mut f:F)
+	{
+		if range.start<range.end
+		{
+			let first=range.start/WORD_BITS;
+			let last=(range.end-1)/WORD_BITS;
+			for i in first..=last
+			{
+				let lo=if i==first {range.start%WORD_BITS} else {0};
+				let hi=if i==last {(range.end-1)%WORD_BITS+1} else {WORD_BITS};
+				if !f(i,Self::mask(lo,hi))
+				{
+					break;
+				}
+			}
+		}
+	}
+
+	// Same as `for_each_word`, but words are visited in reverse order.
+	#[inline(always)] fn for_each_word_rev<F:FnMut(usize,Word)->bool>(range:Range<usize>,mut f:F)
+	{
+		if range.start<range.end
+		{
+			let first=range.start/WORD_BITS;
+			let last=(range.end-1)/WORD_BITS;
+			for i in (first..=last).rev()
+			{
+				let lo=if i==first {range.start%WORD_BITS} else {0};
+				let hi=if i==last {(range.end-1)%WORD_BITS+1} else {WORD_BITS};
+				if !f(i,Self::mask(lo,hi))
+				{
+					break;
+				}
+			}
+		}
+	}
+
+	/// Assigns `true` to all bits in the `range`, one word at a time. \
+	/// Returns `Err(OutOfBitmapError)` if the range goes beyond `N`. In this case, the bitmap is not modified.
+	/// 
+	/// # Panic
+	/// If the `range` does not go beyond `N`, but its starting point is greater than its end point, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{static_bitmap,bitmap::StaticBitmap};
+	/// let mut bmp:static_bitmap!(256)=StaticBitmap::new();
+	/// assert_eq!(bmp.set_range(60..130),Ok(()));
+	/// assert_eq!(bmp.test(59),Ok(false));
+	/// assert_eq!(bmp.test(60),Ok(true));
+	/// assert_eq!(bmp.test(129),Ok(true));
+	/// assert_eq!(bmp.test(130),Ok(false));
+	/// assert!(bmp.set_range(200..=256).is_err());
+	/// ```
+	pub fn set_range<R:RangeBounds<usize>>(&mut self,range:R)->Result<(),OutOfBitmapError>
+	{
+		let r=Self::bit_range(range)?;
+		Self::for_each_word(r,|i,m|
+		{
+			self.write_word(i,self.read_word(i)|m);
+			true
+		});
+		Ok(())
+	}
+
+	/// Assigns `false` to all bits in the `range`, one word at a time. \
+	/// Returns `Err(OutOfBitmapError)` if the range goes beyond `N`. In this case, the bitmap is not modified.
+	/// 
+	/// # Panic
+	/// If the `range` does not go beyond `N`, but its starting point is greater than its end point, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let mut bmp_raw:[u64;4]=[u64::MAX;4];
+	/// let bmp:&mut RefBitmap<256>=unsafe{RefBitmap::from_raw_mut_ptr(bmp_raw.as_mut_ptr().cast())};
+	/// assert_eq!(bmp.reset_range(4..68),Ok(()));
+	/// assert_eq!(bmp_raw,[0xF,0xFFFF_FFFF_FFFF_FFF0,u64::MAX,u64::MAX]);
+	/// ```
+	pub fn reset_range<R:RangeBounds<usize>>(&mut self,range:R)->Result<(),OutOfBitmapError>
+	{
+		let r=Self::bit_range(range)?;
+		Self::for_each_word(r,|i,m|
+		{
+			self.write_word(i,self.read_word(i)&!m);
+			true
+		});
+		Ok(())
+	}
+
+	/// Complements all bits in the `range`, one word at a time. \
+	/// Returns `Err(OutOfBitmapError)` if the range goes beyond `N`. In this case, the bitmap is not modified.
+	/// 
+	/// # Panic
+	/// If the `range` does not go beyond `N`, but its starting point is greater than its end point, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let mut bmp_raw:[u64;2]=[0xFF,0];
+	/// let bmp:&mut RefBitmap<128>=unsafe{RefBitmap::from_raw_mut_ptr(bmp_raw.as_mut_ptr().cast())};
+	/// assert_eq!(bmp.complement_range(4..12),Ok(()));
+	/// assert_eq!(bmp_raw,[0xF0F,0]);
+	/// ```
+	pub fn complement_range<R:RangeBounds<usize>>(&mut self,range:R)->Result<(),OutOfBitmapError>
+	{
+		let r=Self::bit_range(range)?;
+		Self::for_each_word(r,|i,m|
+		{
+			self.write_word(i,self.read_word(i)^m);
+			true
+		});
+		Ok(())
+	}
+
+	/// Tests if all bits in the `range` are set. An empty range is considered as all set. \
+	/// Returns `Err(OutOfBitmapError)` if the range goes beyond `N`.
+	/// 
+	/// # Panic
+	/// If the `range` does not go beyond `N`, but its starting point is greater than its end point, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;2]=[u64::MAX,0x1];
+	/// let bmp:&RefBitmap<128>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.test_range_all(0..65),Ok(true));
+	/// assert_eq!(bmp.test_range_all(0..66),Ok(false));
+	/// ```
+	pub fn test_range_all<R:RangeBounds<usize>>(&self,range:R)->Result<bool,OutOfBitmapError>
+	{
+		let r=Self::bit_range(range)?;
+		let mut result=true;
+		Self::for_each_word(r,|i,m|
+		{
+			result=(self.read_word(i)&m)==m;
+			result
+		});
+		Ok(result)
+	}
+
+	/// Tests if any bit in the `range` is set. An empty range is considered as none set. \
+	/// Returns `Err(OutOfBitmapError)` if the range goes beyond `N`.
+	/// 
+	/// # Panic
+	/// If the `range` does not go beyond `N`, but its starting point is greater than its end point, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;2]=[0,0x100];
+	/// let bmp:&RefBitmap<128>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.test_range_any(0..72),Ok(false));
+	/// assert_eq!(bmp.test_range_any(0..73),Ok(true));
+	/// ```
+	pub fn test_range_any<R:RangeBounds<usize>>(&self,range:R)->Result<bool,OutOfBitmapError>
+	{
+		let r=Self::bit_range(range)?;
+		let mut result=false;
+		Self::for_each_word(r,|i,m|
+		{
+			result=(self.read_word(i)&m)!=0;
+			!result
+		});
+		Ok(result)
+	}
+
+	/// Counts the number of set bits in the bitmap. \
+	/// Bits beyond `N` in the final word are not counted.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;2]=[0xF0,u64::MAX];
+	/// let bmp:&RefBitmap<100>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.count_ones(),40);
+	/// ```
+	pub fn count_ones(&self)->usize
+	{
+		let mut count=0;
+		Self::for_each_word(0..N,|i,m|
+		{
+			count+=(self.read_word(i)&m).count_ones() as usize;
+			true
+		});
+		count
+	}
+
+	/// Counts the number of cleared bits in the bitmap. \
+	/// Bits beyond `N` in the final word are not counted.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;2]=[0xF0,u64::MAX];
+	/// let bmp:&RefBitmap<100>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.count_zeros(),60);
+	/// ```
+	pub fn count_zeros(&self)->usize
+	{
+		N-self.count_ones()
+	}
+
+	/// Assigns `true` to all bits in the bitmap. \
+	/// Bits beyond `N` in the final word are not modified.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let mut bmp_raw:[u64;2]=[0;2];
+	/// let bmp:&mut RefBitmap<100>=unsafe{RefBitmap::from_raw_mut_ptr(bmp_raw.as_mut_ptr().cast())};
+	/// bmp.fill();
+	/// assert_eq!(bmp_raw,[u64::MAX,0xF_FFFF_FFFF]);
+	/// ```
+	pub fn fill(&mut self)
+	{
+		Self::for_each_word(0..N,|i,m|
+		{
+			self.write_word(i,self.read_word(i)|m);
+			true
+		});
+	}
+
+	/// Assigns `false` to all bits in the bitmap. \
+	/// Bits beyond `N` in the final word are not modified.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let mut bmp_raw:[u64;2]=[u64::MAX;2];
+	/// let bmp:&mut RefBitmap<100>=unsafe{RefBitmap::from_raw_mut_ptr(bmp_raw.as_mut_ptr().cast())};
+	/// bmp.clear_all();
+	/// assert_eq!(bmp_raw,[0,0xFFFF_FFF0_0000_0000]);
+	/// ```
+	pub fn clear_all(&mut self)
+	{
+		Self::for_each_word(0..N,|i,m|
+		{
+			self.write_word(i,self.read_word(i)&!m);
+			true
+		});
+	}
+
+	// Finds the first bit equal to `value` in `range`. It is required that `range.end<=N`.
+	fn find_forward(&self,range:Range<usize>,value:bool)->Option<usize>
+	{
+		let mut result=None;
+		Self::for_each_word(range,|i,m|
+		{
+			let w=if value {self.read_word(i)} else {!self.read_word(i)};
+			result=bit_scan_forward(w&m).map(|j| i*WORD_BITS+j);
+			result.is_none()
+		});
+		result
+	}
+
+	// Finds the last bit equal to `value` in `range`. It is required that `range.end<=N`.
+	fn find_backward(&self,range:Range<usize>,value:bool)->Option<usize>
+	{
+		let mut result=None;
+		Self::for_each_word_rev(range,|i,m|
+		{
+			let w=if value {self.read_word(i)} else {!self.read_word(i)};
+			result=bit_scan_reverse(w&m).map(|j| i*WORD_BITS+j);
+			result.is_none()
+		});
+		result
+	}
+
+	// Finds the first run of `len` bits equal to `value`, starting at a multiple of `align`, at or after `start`.
+	fn find_run_forward(&self,len:usize,align:usize,start:usize,value:bool)->Option<usize>
+	{
+		assert!(align!=0,"Alignment must not be zero!");
+		let mut pos=start.checked_next_multiple_of(align)?;
+		loop
+		{
+			let end=pos.checked_add(len)?;
+			if end>N
+			{
+				return None;
+			}
+			match self.find_forward(pos..end,!value)
+			{
+				// Skip the mismatched bits, and align the beginning of the next candidate.
+				Some(q)=>pos=self.find_forward(q+1..N,value)?.checked_next_multiple_of(align)?,
+				None=>return Some(pos)
+			}
+		}
+	}
+
+	// Finds the last run of `len` bits equal to `value`, starting at a multiple of `align`, ending at or before `end`.
+	fn find_run_backward(&self,len:usize,align:usize,end:usize,value:bool)->Option<usize>
+	{
+		assert!(align!=0,"Alignment must not be zero!");
+		let mut end=if end>N {N} else {end};
+		loop
+		{
+			if len>end
+			{
+				return None;
+			}
+			let pos=end-len;
+			let pos=pos-pos%align;
+			match self.find_backward(pos..pos+len,!value)
+			{
+				// Skip the mismatched bits, and the next candidate must end before them.
+				Some(q)=>end=self.find_backward(0..q,value)?+1,
+				None=>return Some(pos)
+			}
+		}
+	}
+
+	/// Search for `len` consecutive cleared bits in the bitmap in forward direction. \
+	/// The run must begin at a multiple of `align` and at or after position `start`. \
+	/// Returns `Some(usize)` with the beginning of the run if there is such a run. \
+	/// Returns `None` if there is no such run.
+	/// 
+	/// # Panic
+	/// If `align` is zero, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;4]=[u64::MAX,0xFF00FF,0,0];
+	/// let bmp:&RefBitmap<256>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.search_cleared_run(8,1,0),Some(72));
+	/// assert_eq!(bmp.search_cleared_run(9,1,0),Some(88));
+	/// assert_eq!(bmp.search_cleared_run(8,16,0),Some(96));
+	/// assert_eq!(bmp.search_cleared_run(8,1,100),Some(100));
+	/// assert_eq!(bmp.search_cleared_run(200,1,0),None);
+	/// ```
+	pub fn search_cleared_run(&self,len:usize,align:usize,start:usize)->Option<usize>
+	{
+		self.find_run_forward(len,align,start,false)
+	}
+
+	/// Search for `len` consecutive set bits in the bitmap in forward direction. \
+	/// The run must begin at a multiple of `align` and at or after position `start`. \
+	/// Returns `Some(usize)` with the beginning of the run if there is such a run. \
+	/// Returns `None` if there is no such run.
+	/// 
+	/// # Panic
+	/// If `align` is zero, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;4]=[0,0xFF00FF,u64::MAX,0];
+	/// let bmp:&RefBitmap<256>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.search_set_run(8,1,0),Some(64));
+	/// assert_eq!(bmp.search_set_run(9,1,0),Some(128));
+	/// assert_eq!(bmp.search_set_run(8,16,65),Some(80));
+	/// ```
+	pub fn search_set_run(&self,len:usize,align:usize,start:usize)->Option<usize>
+	{
+		self.find_run_forward(len,align,start,true)
+	}
+
+	/// Search for `len` consecutive cleared bits in the bitmap in backward direction. \
+	/// The run must begin at a multiple of `align` and end at or before position `end`. \
+	/// Returns `Some(usize)` with the beginning of the run if there is such a run. \
+	/// Returns `None` if there is no such run.
+	/// 
+	/// # Panic
+	/// If `align` is zero, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;4]=[0,0xFF00FF,u64::MAX,u64::MAX];
+	/// let bmp:&RefBitmap<256>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.search_cleared_run_backward(8,1,256),Some(120));
+	/// assert_eq!(bmp.search_cleared_run_backward(8,1,80),Some(72));
+	/// assert_eq!(bmp.search_cleared_run_backward(16,16,80),Some(48));
+	/// ```
+	pub fn search_cleared_run_backward(&self,len:usize,align:usize,end:usize)->Option<usize>
+	{
+		self.find_run_backward(len,align,end,false)
+	}
+
+	/// Search for `len` consecutive set bits in the bitmap in backward direction. \
+	/// The run must begin at a multiple of `align` and end at or before position `end`. \
+	/// Returns `Some(usize)` with the beginning of the run if there is such a run. \
+	/// Returns `None` if there is no such run.
+	/// 
+	/// # Panic
+	/// If `align` is zero, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;4]=[u64::MAX,0xFF00FF,0,0];
+	/// let bmp:&RefBitmap<256>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.search_set_run_backward(8,1,256),Some(80));
+	/// assert_eq!(bmp.search_set_run_backward(9,1,256),Some(63));
+	/// assert_eq!(bmp.search_set_run_backward(9,8,256),Some(56));
+	/// ```
+	pub fn search_set_run_backward(&self,len:usize,align:usize,end:usize)->Option<usize>
+	{
+		self.find_run_backward(len,align,end,true)
+	}
+
+	// Reads word `index` with bits equal to `value` set, and bits beyond `N` cleared.
+	#[inline(always)] fn read_word_matching(&self,index:usize,value:bool)->Word
+	{
+		let w=if value {self.read_word(index)} else {!self.read_word(index)};
+		let rest=N-index*WORD_BITS;
+		if rest<WORD_BITS {w&Self::mask(0,rest)} else {w}
+	}
+
+	/// Search for a set bit in the bitmap at or after position `position`. \
+	/// Returns `Some(usize)` if there is a set bit. \
+	/// Returns `None` if all bits from `position` are cleared.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;4]=[0x11,0,0x1,0];
+	/// let bmp:&RefBitmap<256>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.next_set_from(0),Some(0));
+	/// assert_eq!(bmp.next_set_from(1),Some(4));
+	/// assert_eq!(bmp.next_set_from(5),Some(128));
+	/// assert_eq!(bmp.next_set_from(129),None);
+	/// ```
+	pub fn next_set_from(&self,position:usize)->Option<usize>
+	{
+		if position<N {self.find_forward(position..N,true)} else {None}
+	}
+
+	/// Search for a cleared bit in the bitmap at or after position `position`. \
+	/// Returns `Some(usize)` if there is a cleared bit. \
+	/// Returns `None` if all bits from `position` are set.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;2]=[u64::MAX,0xF];
+	/// let bmp:&RefBitmap<128>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.next_cleared_from(0),Some(68));
+	/// assert_eq!(bmp.next_cleared_from(100),Some(100));
+	/// ```
+	pub fn next_cleared_from(&self,position:usize)->Option<usize>
+	{
+		if position<N {self.find_forward(position..N,false)} else {None}
+	}
+
+	/// Search for a set bit in the bitmap before position `position`. \
+	/// Returns `Some(usize)` if there is a set bit. \
+	/// Returns `None` if all bits before `position` are cleared.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;4]=[0x11,0,0x1,0];
+	/// let bmp:&RefBitmap<256>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.prev_set_before(256),Some(128));
+	/// assert_eq!(bmp.prev_set_before(128),Some(4));
+	/// assert_eq!(bmp.prev_set_before(4),Some(0));
+	/// assert_eq!(bmp.prev_set_before(0),None);
+	/// ```
+	pub fn prev_set_before(&self,position:usize)->Option<usize>
+	{
+		self.find_backward(0..if position<N {position} else {N},true)
+	}
+
+	/// Search for a cleared bit in the bitmap before position `position`. \
+	/// Returns `Some(usize)` if there is a cleared bit. \
+	/// Returns `None` if all bits before `position` are set.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;2]=[0xFFFF_FFFF_FFFF_FFF0,u64::MAX];
+	/// let bmp:&RefBitmap<128>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert_eq!(bmp.prev_cleared_before(128),Some(3));
+	/// ```
+	pub fn prev_cleared_before(&self,position:usize)->Option<usize>
+	{
+		self.find_backward(0..if position<N {position} else {N},false)
+	}
+
+	/// Returns an iterator of the positions of all set bits in ascending order.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;2]=[0x8001,0x2];
+	/// let bmp:&RefBitmap<128>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert!(bmp.iter_ones().eq([0,15,65]));
+	/// ```
+	pub fn iter_ones(&self)->BitIter<'_,N>
+	{
+		BitIter::new(self,true)
+	}
+
+	/// Returns an iterator of the positions of all cleared bits in ascending order.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::RefBitmap;
+	/// let bmp_raw:[u64;1]=[0xFFFF_FFFF_FFFF_FF7E];
+	/// let bmp:&RefBitmap<64>=unsafe{RefBitmap::from_raw_ptr(bmp_raw.as_ptr().cast())};
+	/// assert!(bmp.iter_zeros().eq([0,7]));
+	/// ```
+	pub fn iter_zeros(&self)->BitIter<'_,N>
+	{
+		BitIter::new(self,false)
+	}
+
+	/// Tests if all set bits in this bitmap are also set in `other`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{static_bitmap,bitmap::StaticBitmap};
+	/// let a:static_bitmap!(100)=StaticBitmap::from_words([0b0110,0]);
+	/// let b:static_bitmap!(100)=StaticBitmap::from_words([0b1110,0]);
+	/// assert!(a.is_subset(&b));
+	/// assert!(!b.is_subset(&a));
+	/// ```
+	pub fn is_subset(&self,other:&RefBitmap<N>)->bool
+	{
+		let mut result=true;
+		Self::for_each_word(0..N,|i,m|
+		{
+			result=(self.read_word(i)&!other.read_word(i)&m)==0;
+			result
+		});
+		result
+	}
+
+	/// Tests if this bitmap and `other` have no set bits in common.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{static_bitmap,bitmap::StaticBitmap};
+	/// let a:static_bitmap!(100)=StaticBitmap::from_words([0b0110,0]);
+	/// let b:static_bitmap!(100)=StaticBitmap::from_words([0b1001,0]);
+	/// assert!(a.is_disjoint(&b));
+	/// assert!(!a.is_disjoint(&a));
+	/// ```
+	pub fn is_disjoint(&self,other:&RefBitmap<N>)->bool
+	{
+		let mut result=true;
+		Self::for_each_word(0..N,|i,m|
+		{
+			result=(self.read_word(i)&other.read_word(i)&m)==0;
+			result
+		});
+		result
+	}
+
+	/// Counts the number of bits set in both this bitmap and `other`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{static_bitmap,bitmap::StaticBitmap};
+	/// let a:static_bitmap!(100)=StaticBitmap::from_words([0b0110,1]);
+	/// let b:static_bitmap!(100)=StaticBitmap::from_words([0b1100,1]);
+	/// assert_eq!(a.intersection_count(&b),2);
+	/// ```
+	pub fn intersection_count(&self,other:&RefBitmap<N>)->usize
+	{
+		let mut count=0;
+		Self::for_each_word(0..N,|i,m|
+		{
+			count+=(self.read_word(i)&other.read_word(i)&m).count_ones() as usize;
+			true
+		});
+		count
+	}
+}
+
+// Implements a bitwise operator between bitmaps of equal `N`.
+// For `RefBitmap`, only the assignment form is available, and bits beyond `N` in the final word are not modified.
+// For `StaticBitmap`, bits beyond `N` are always kept cleared.
+macro_rules! impl_bitmap_op
+{
+	($op:ident,$f:ident,$assign_op:ident,$assign_f:ident,$x:tt)=>
+	{
+		impl<const N:usize> $assign_op<&RefBitmap<N>> for RefBitmap<N>
+		{
+			fn $assign_f(&mut self, rhs: &RefBitmap<N>)
+			{
+				Self::for_each_word(0..N,|i,m|
+				{
+					let v=self.read_word(i);
+					self.write_word(i,(v&!m)|((v $x rhs.read_word(i))&m));
+					true
+				});
+			}
+		}
+
+		impl<const N:usize,const W:usize> $assign_op<&StaticBitmap<N,W>> for StaticBitmap<N,W>
+		{
+			fn $assign_f(&mut self, rhs: &StaticBitmap<N,W>)
+			{
+				for (a,b) in self.words.iter_mut().zip(rhs.words.iter())
+				{
+					*a=*a $x *b;
+				}
+			}
+		}
+
+		impl<const N:usize,const W:usize> $assign_op for StaticBitmap<N,W>
+		{
+			fn $assign_f(&mut self, rhs: StaticBitmap<N,W>)
+			{
+				$assign_op::$assign_f(self,&rhs);
+			}
+		}
+
+		impl<const N:usize,const W:usize> $op for StaticBitmap<N,W>
+		{
+			type Output = StaticBitmap<N,W>;
+
+			fn $f(mut self, rhs: StaticBitmap<N,W>) -> Self::Output
+			{
+				$assign_op::$assign_f(&mut self,&rhs);
+				self
+			}
+		}
+
+		impl<const N:usize,const W:usize> $op for &StaticBitmap<N,W>
+		{
+			type Output = StaticBitmap<N,W>;
+
+			fn $f(self, rhs: &StaticBitmap<N,W>) -> Self::Output
+			{
+				let mut r=self.clone();
+				$assign_op::$assign_f(&mut r,rhs);
+				r
+			}
+		}
+	};
+}
+
+impl_bitmap_op!(BitAnd,bitand,BitAndAssign,bitand_assign,&);
+impl_bitmap_op!(BitOr,bitor,BitOrAssign,bitor_assign,|);
+impl_bitmap_op!(BitXor,bitxor,BitXorAssign,bitxor_assign,^);
+
+impl<const N:usize,const W:usize> Not for StaticBitmap<N,W>
+{
+	type Output = StaticBitmap<N,W>;
+
+	fn not(self) -> Self::Output
+	{
+		// Bits beyond `N` must remain cleared.
+		Self::from_words(self.words.map(|w| !w))
+	}
+}
+
+impl<const N:usize,const W:usize> Not for &StaticBitmap<N,W>
+{
+	type Output = StaticBitmap<N,W>;
+
+	fn not(self) -> Self::Output
+	{
+		!self.clone()
+	}
+}
+
+// A static-bitmap is serialized as a sequence of `ceil(N/8)` bytes in little-endian bit order.
+// The layout does not depend on the width of `usize`, so data can be exchanged between 32-bit and 64-bit targets.
+#[cfg(feature = "serde")]
+impl<const N:usize,const W:usize> serde::Serialize for StaticBitmap<N,W>
+{
+	fn serialize<S:serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		const WORD_BYTES:usize=size_of::<usize>();
+		serializer.collect_seq((0..N.div_ceil(8)).map(|i| (self.words[i/WORD_BYTES]>>(i%WORD_BYTES*8)) as u8))
+	}
+}
+
+// Deserializing fails with an `invalid_length` error if the number of bytes mismatches,
+// or with an `invalid_value` error if any bit beyond `N` is set.
+#[cfg(feature = "serde")]
+impl<'de,const N:usize,const W:usize> serde::Deserialize<'de> for StaticBitmap<N,W>
+{
+	fn deserialize<D:serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+	{
+		deserializer.deserialize_seq(StaticBitmapVisitor)
+	}
+}
+
+#[cfg(feature = "serde")]
+struct StaticBitmapVisitor<const N:usize,const W:usize>;
+
+#[cfg(feature = "serde")]
+impl<const N:usize,const W:usize> StaticBitmapVisitor<N,W>
+{
+	// Puts the byte at location `index` into the bitmap.
+	fn put<E:serde::de::Error>(&self,bmp:&mut StaticBitmap<N,W>,index:usize,byte:u8)->Result<(),E>
+	{
+		const WORD_BYTES:usize=size_of::<usize>();
+		if index==N/8 && byte>>(N%8)!=0
+		{
+			Err(E::invalid_value(serde::de::Unexpected::Unsigned(byte as u64),self))
+		}
+		else
+		{
+			bmp.words[index/WORD_BYTES]|=(byte as usize)<<(index%WORD_BYTES*8);
+			Ok(())
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de,const N:usize,const W:usize> serde::de::Visitor<'de> for StaticBitmapVisitor<N,W>
+{
+	type Value = StaticBitmap<N,W>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(formatter,"a sequence of {} bytes without bits beyond {N}",N.div_ceil(8))
+	}
+
+	fn visit_seq<A:serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	{
+		let mut bmp=StaticBitmap::new();
+		for i in 0..N.div_ceil(8)
+		{
+			match seq.next_element::<u8>()?
+			{
+				Some(b)=>self.put(&mut bmp,i,b)?,
+				None=>return Err(serde::de::Error::invalid_length(i,&self))
+			}
+		}
+		if seq.next_element::<u8>()?.is_some()
+		{
+			// The exact length is unknown, but there is at least one more byte.
+			return Err(serde::de::Error::invalid_length(N.div_ceil(8)+1,&self));
+		}
+		Ok(bmp)
+	}
+
+	fn visit_bytes<E:serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E>
+	{
+		if v.len()!=N.div_ceil(8)
+		{
+			return Err(E::invalid_length(v.len(),&self));
+		}
+		let mut bmp=StaticBitmap::new();
+		for (i,&b) in v.iter().enumerate()
+		{
+			self.put(&mut bmp,i,b)?;
+		}
+		Ok(bmp)
+	}
+}
+
+/// The iterator of positions of either set or cleared bits in a `RefBitmap`. \
+/// Use `RefBitmap::iter_ones` or `RefBitmap::iter_zeros` to create this iterator.
+pub struct BitIter<'a,const N:usize>
+{
+	bitmap:&'a RefBitmap<N>,
+	value:bool,
+	index:usize,
+	// Remaining bits in the current word.
+	word:Word
+}
+
+impl<'a,const N:usize> BitIter<'a,N>
+{
+	fn new(bitmap:&'a RefBitmap<N>,value:bool)->Self
+	{
+		Self
+		{
+			bitmap,
+			value,
+			index:0,
+			word:if N>0 {bitmap.read_word_matching(0,value)} else {0}
+		}
+	}
+}
+
+impl<'a,const N:usize> Iterator for BitIter<'a,N>
+{
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		while self.word==0
+		{
+			if (self.index+1)*WORD_BITS>=N
+			{
+				return None;
+			}
+			self.index+=1;
+			self.word=self.bitmap.read_word_matching(self.index,self.value);
+		}
+		let j=self.word.trailing_zeros() as usize;
+		// Clear the lowest set bit.
+		self.word&=self.word-1;
+		Some(self.index*WORD_BITS+j)
+	}
+}
+
+impl<'a,const N:usize> FusedIterator for BitIter<'a,N> {}
+
+/// The ZST `AtomicRefBitmap` reference with `N` bits, which can be shared between processors. \
+/// All operations take `&self` and are performed with atomic instructions, so the bitmap can be placed in a `static`.
+/// 
+/// To create an atomic ref-bitmap, use `from_raw_ptr`, or use the owned `AtomicStaticBitmap` type.
+pub struct AtomicRefBitmap<const N:usize>;
+
+impl<'a,const N:usize> AtomicRefBitmap<N>
+{
+	/// Creates an `AtomicRefBitmap` from raw pointer.
+	/// 
+	/// # Safety
+	/// You must ensure `ptr` points to a valid buffer which has at least `N` bits!
+	pub unsafe fn from_raw_ptr(ptr:*const AtomicUsize)->&'a Self
+	{
+		unsafe
+		{
+			&*ptr.cast()
+		}
+	}
+}
+
+impl<const N:usize> AtomicRefBitmap<N>
+{
+	const BITS:usize=usize::BITS as usize;
+
+	#[inline(always)] fn word(&self,index:usize)->&AtomicUsize
+	{
+		let bmp:*const AtomicUsize=(&raw const *self).cast();
+		unsafe
+		{
+			&*bmp.add(index)
+		}
+	}
+
+	// Atomically sets the bit `j` in word `i` and returns the previous value.
+	#[inline(always)] fn atomic_set(&self,i:usize,j:usize)->bool
+	{
+		#[cfg(target_arch="x86_64")]
+		{
+			// In x86-64, use the `lock bts` instruction.
+			let r:u8;
+			unsafe
+			{
+				asm!
+				(
+					"lock bts qword ptr [{p}],{b}",
+					"setc {r}",
+					p=in(reg) self.word(i).as_ptr(),
+					b=in(reg) j as u64,
+					r=out(reg_byte) r,
+					options(nostack)
+				);
+			}
+			r!=0
+		}
+		#[cfg(target_arch="x86")]
+		{
+			// In x86, use the `lock bts` instruction.
+			let r:u8;
+			unsafe
+			{
+				asm!
+				(
+					"lock bts dword ptr [{p}],{b}",
+					"setc {r}",
+					p=in(reg) self.word(i).as_ptr(),
+					b=in(reg) j as u32,
+					r=out(reg_byte) r,
+					options(nostack)
+				);
+			}
+			r!=0
+		}
+		#[cfg(all(not(target_arch="x86_64"),not(target_arch="x86")))]
+		{
+			// Unknown CPU architecture. Use the generic method.
+			let v=1<<j;
+			(self.word(i).fetch_or(v,Ordering::AcqRel)&v)!=0
+		}
+	}
+
+	// Atomically resets the bit `j` in word `i` and returns the previous value.
+	#[inline(always)] fn atomic_reset(&self,i:usize,j:usize)->bool
+	{
+		#[cfg(target_arch="x86_64")]
+		{
+			// In x86-64, use the `lock btr` instruction.
+			let r:u8;
+			unsafe
+			{
+				asm!
+				(
+					"lock btr qword ptr [{p}],{b}",
+					"setc {r}",
+					p=in(reg) self.word(i).as_ptr(),
+					b=in(reg) j as u64,
+					r=out(reg_byte) r,
+					options(nostack)
+				);
+			}
+			r!=0
+		}
+		#[cfg(target_arch="x86")]
+		{
+			// In x86, use the `lock btr` instruction.
+			let r:u8;
+			unsafe
+			{
+				asm!
+				(
+					"lock btr dword ptr [{p}],{b}",
+					"setc {r}",
+					p=in(reg) self.word(i).as_ptr(),
+					b=in(reg) j as u32,
+					r=out(reg_byte) r,
+					options(nostack)
+				);
+			}
+			r!=0
+		}
+		#[cfg(all(not(target_arch="x86_64"),not(target_arch="x86")))]
+		{
+			// Unknown CPU architecture. Use the generic method.
+			let v=1<<j;
+			(self.word(i).fetch_and(!v,Ordering::AcqRel)&v)!=0
+		}
+	}
+
+	/// Tests if a position in the bitmap is set. \
+	/// Returns `Ok(bool) if `position<N`. The `bool` specifies whether the bit is set or not. \
+	/// Returns `Err(OutOfBitmapError) if `position>=N`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::*;
+	/// let bmp:AtomicStaticBitmap<100,{bitmap_words(100)}>=AtomicStaticBitmap::new();
+	/// assert_eq!(bmp.test(36),Ok(false));
+	/// assert_eq!(bmp.test(100),Err(OutOfBitmapError::new(100,100)));
+	/// ```
+	pub fn test(&self,position:usize)->Result<bool,OutOfBitmapError>
+	{
+		if position<N
+		{
+			let v=self.word(position/Self::BITS).load(Ordering::Acquire);
+			Ok((v>>(position%Self::BITS))&1!=0)
+		}
+		else
+		{
+			Err(OutOfBitmapError::new(position,N))
+		}
+	}
+
+	/// Atomically tests and assigns `true` to a position in the bitmap and returns the previous value. \
+	/// Returns `Ok(bool) if `position<N`. The `bool` specifies whether the bit is set or not. \
+	/// Returns `Err(OutOfBitmapError) if `position>=N`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::*;
+	/// let bmp:AtomicStaticBitmap<100,{bitmap_words(100)}>=AtomicStaticBitmap::new();
+	/// assert_eq!(bmp.test_and_set(36),Ok(false));
+	/// assert_eq!(bmp.test_and_set(36),Ok(true));
+	/// ```
+	pub fn test_and_set(&self,position:usize)->Result<bool,OutOfBitmapError>
+	{
+		if position<N
+		{
+			Ok(self.atomic_set(position/Self::BITS,position%Self::BITS))
+		}
+		else
+		{
+			Err(OutOfBitmapError::new(position,N))
+		}
+	}
+
+	/// Atomically tests and assigns `false` to a position in the bitmap and returns the previous value. \
+	/// Returns `Ok(bool) if `position<N`. The `bool` specifies whether the bit is set or not. \
+	/// Returns `Err(OutOfBitmapError) if `position>=N`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::*;
+	/// let bmp:AtomicStaticBitmap<100,{bitmap_words(100)}>=AtomicStaticBitmap::new();
+	/// assert_eq!(bmp.test_and_set(36),Ok(false));
+	/// assert_eq!(bmp.test_and_reset(36),Ok(true));
+	/// assert_eq!(bmp.test_and_reset(36),Ok(false));
+	/// ```
+	pub fn test_and_reset(&self,position:usize)->Result<bool,OutOfBitmapError>
+	{
+		if position<N
+		{
+			Ok(self.atomic_reset(position/Self::BITS,position%Self::BITS))
+		}
+		else
+		{
+			Err(OutOfBitmapError::new(position,N))
+		}
+	}
+
+	/// Atomically claims the first cleared bit in the bitmap, by setting it. \
+	/// Returns `Some(usize)` with the claimed position if there is a cleared bit. \
+	/// Returns `None` if all bits in bitmap are set.
+	/// 
+	/// A bit claimed by this method is never claimed by any other processor until it is reset.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::*;
+	/// let bmp:AtomicStaticBitmap<2,{bitmap_words(2)}>=AtomicStaticBitmap::new();
+	/// assert_eq!(bmp.claim_first_cleared(),Some(0));
+	/// assert_eq!(bmp.claim_first_cleared(),Some(1));
+	/// assert_eq!(bmp.claim_first_cleared(),None);
+	/// assert_eq!(bmp.test_and_reset(0),Ok(true));
+	/// assert_eq!(bmp.claim_first_cleared(),Some(0));
+	/// ```
+	pub fn claim_first_cleared(&self)->Option<usize>
+	{
+		for i in 0..N.div_ceil(Self::BITS)
+		{
+			let rest=N-i*Self::BITS;
+			let valid=if rest<Self::BITS {(1<<rest)-1} else {usize::MAX};
+			let word=self.word(i);
+			let mut current=word.load(Ordering::Relaxed);
+			loop
+			{
+				let free=!current&valid;
+				if free==0
+				{
+					break;
+				}
+				let j=free.trailing_zeros() as usize;
+				match word.compare_exchange_weak(current,current|(1<<j),Ordering::AcqRel,Ordering::Relaxed)
+				{
+					Ok(_)=>return Some(i*Self::BITS+j),
+					// Another processor modified this word. Try again with the new value.
+					Err(v)=>current=v
+				}
+			}
+		}
+		None
+	}
+
+	/// Counts the number of set bits in the bitmap. \
+	/// If the bitmap is being modified concurrently, the result is only a snapshot.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::bitmap::*;
+	/// let bmp:AtomicStaticBitmap<100,{bitmap_words(100)}>=AtomicStaticBitmap::new();
+	/// bmp.test_and_set(3).unwrap();
+	/// bmp.test_and_set(99).unwrap();
+	/// assert_eq!(bmp.count_ones(),2);
+	/// ```
+	pub fn count_ones(&self)->usize
+	{
+		let mut count=0;
+		for i in 0..N.div_ceil(Self::BITS)
+		{
+			let rest=N-i*Self::BITS;
+			let valid=if rest<Self::BITS {(1<<rest)-1} else {usize::MAX};
+			count+=(self.word(i).load(Ordering::Relaxed)&valid).count_ones() as usize;
+		}
+		count
+	}
+}
+
+/// The `AtomicStaticBitmap` is an owned atomic bitmap with `N` bits, which stores its own `W` words. \
+/// The `W` must be equal to `bitmap_words(N)`, otherwise the compilation would fail.
+/// 
+/// The `AtomicStaticBitmap` dereferences to `AtomicRefBitmap<N>`, and it can be placed in a `static`.
+/// 
+/// # Example
+/// ```
+/// use static_collections::bitmap::*;
+/// static SLOTS:AtomicStaticBitmap<64,{bitmap_words(64)}>=AtomicStaticBitmap::new();
+/// let slot=SLOTS.claim_first_cleared().unwrap();
+/// assert_eq!(SLOTS.test(slot),Ok(true));
+/// ```
+pub struct AtomicStaticBitmap<const N:usize,const W:usize>
+{
+	words:[AtomicUsize;W]
+}
+
+impl<const N:usize,const W:usize> AtomicStaticBitmap<N,W>
+{
+	const WORDS_CHECK:()=assert!(W==bitmap_words(N),"The number of words does not match the number of bits!");
+
+	/// Creates a new `AtomicStaticBitmap` with all bits cleared.
+	pub const fn new()->Self
+	{
+		let _:()=Self::WORDS_CHECK;
+		Self
+		{
+			words:[const {AtomicUsize::new(0)};W]
+		}
+	}
+
+	/// Returns an `AtomicRefBitmap` reference to this bitmap.
+	pub const fn as_ref_bitmap(&self)->&AtomicRefBitmap<N>
+	{
+		unsafe
+		{
+			&*self.words.as_ptr().cast()
+		}
+	}
+}
+
+impl<const N:usize,const W:usize> Default for AtomicStaticBitmap<N,W>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,const W:usize> Deref for AtomicStaticBitmap<N,W>
+{
+	type Target = AtomicRefBitmap<N>;
+
+	fn deref(&self) -> &Self::Target
+	{
+		self.as_ref_bitmap()
+	}
+}
+
+impl<const N:usize,const W:usize> fmt::Debug for AtomicStaticBitmap<N,W>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_struct("AtomicStaticBitmap").field("words",&self.words).finish()
+	}
+}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::{thread, vec::Vec};
+	use crate::{bitmap::{AtomicStaticBitmap, RefBitmap, StaticBitmap, bitmap_words}, xorshift};
+
+	fn brute_run(bmp:&StaticBitmap<200,{crate::bitmap::bitmap_words(200)}>,len:usize,align:usize,value:bool,range:core::ops::Range<usize>,backward:bool)->Option<usize>
+	{
+		let fits=|p:usize| p.is_multiple_of(align) && p+len<=range.end && (p..p+len).all(|i| bmp.test(i)==Ok(value));
+		if backward
+		{
+			(0..=range.end).rev().find(|&p| fits(p))
+		}
+		else
+		{
+			(range.start..=range.end).find(|&p| fits(p))
+		}
+	}
+
+	#[test] fn bit_iter()
+	{
+		let mut state=0x0F1E_2D3C_4B5A_6978;
+		for _ in 0..50
+		{
+			let mut bmp:StaticBitmap<200,{crate::bitmap::bitmap_words(200)}>=StaticBitmap::new();
+			for _ in 0..(xorshift(&mut state)%100)
+			{
+				bmp.set((xorshift(&mut state)%200) as usize).unwrap();
+			}
+			assert!(bmp.iter_ones().eq((0..200).filter(|&i| bmp.test(i)==Ok(true))));
+			assert!(bmp.iter_zeros().eq((0..200).filter(|&i| bmp.test(i)==Ok(false))));
+			for p in 0..=200
+			{
+				assert_eq!(bmp.next_set_from(p),(p..200).find(|&i| bmp.test(i)==Ok(true)));
+				assert_eq!(bmp.next_cleared_from(p),(p..200).find(|&i| bmp.test(i)==Ok(false)));
+				assert_eq!(bmp.prev_set_before(p),(0..p).rev().find(|&i| bmp.test(i)==Ok(true)));
+				assert_eq!(bmp.prev_cleared_before(p),(0..p).rev().find(|&i| bmp.test(i)==Ok(false)));
+			}
+		}
+	}
+
+	#[test] fn run_search()
+	{
+		let mut state=0x1234_5678_9ABC_DEF0;
+		for round in 0..200
+		{
+			let mut bmp:StaticBitmap<200,{crate::bitmap::bitmap_words(200)}>=StaticBitmap::new();
+			// Use runs of random lengths so that long runs of both values exist.
+			let mut p=0;
+			while p<200
+			{
+				let l=(xorshift(&mut state)%(round%40+1)) as usize;
+				if xorshift(&mut state)&1!=0
+				{
+					bmp.set_range(p..(p+l).min(200)).unwrap();
+				}
+				p+=l+1;
+			}
+			for _ in 0..20
+			{
+				let len=(xorshift(&mut state)%24) as usize;
+				let align=[1,2,3,8,16][(xorshift(&mut state)%5) as usize];
+				let pos=(xorshift(&mut state)%210) as usize;
+				assert_eq!(bmp.search_cleared_run(len,align,pos),brute_run(&bmp,len,align,false,pos..200,false));
+				assert_eq!(bmp.search_set_run(len,align,pos),brute_run(&bmp,len,align,true,pos..200,false));
+				assert_eq!(bmp.search_cleared_run_backward(len,align,pos),brute_run(&bmp,len,align,false,0..pos.min(200),true));
+				assert_eq!(bmp.search_set_run_backward(len,align,pos),brute_run(&bmp,len,align,true,0..pos.min(200),true));
+			}
+		}
+	}
+
+	#[test] fn set_algebra()
+	{
+		let a:StaticBitmap<70,{bitmap_words(70)}>=StaticBitmap::from_words([usize::MAX;bitmap_words(70)]);
+		let mut b:StaticBitmap<70,{bitmap_words(70)}>=StaticBitmap::new();
+		b.set_range(10..20).unwrap();
+		let c=!&b;
+		assert_eq!(c.count_ones(),60);
+		assert_eq!(c.as_words(),(&a^&b).as_words());
+		assert_eq!(&c|&b,a);
+		assert_eq!((c.clone()&b.clone()).count_ones(),0);
+		assert!(c.is_disjoint(&b) && b.is_subset(&a) && !a.is_subset(&b));
+		assert_eq!(a.intersection_count(&b),10);
+		// Bits beyond `N` are not touched by `RefBitmap` operators.
+		let mut raw:[u64;2]=[0,u64::MAX<<6];
+		let r:&mut RefBitmap<70>=unsafe{RefBitmap::from_raw_mut_ptr(raw.as_mut_ptr().cast())};
+		*r^=unsafe{RefBitmap::from_raw_ptr([u64::MAX;2].as_ptr().cast())};
+		assert_eq!(raw,[u64::MAX,(u64::MAX<<6)|0x3F]);
+		let r:&mut RefBitmap<70>=unsafe{RefBitmap::from_raw_mut_ptr(raw.as_mut_ptr().cast())};
+		*r&=unsafe{RefBitmap::from_raw_ptr([0u64;2].as_ptr().cast())};
+		assert_eq!(raw,[0,u64::MAX<<6]);
+	}
+
+	#[test] fn concurrent_claim()
+	{
+		let bmp:AtomicStaticBitmap<300,{bitmap_words(300)}>=AtomicStaticBitmap::new();
+		let claimed:Vec<Vec<usize>>=thread::scope(|s|
+		{
+			let handles:Vec<_>=(0..4).map(|_| s.spawn(||
+			{
+				let mut v=Vec::new();
+				while let Some(p)=bmp.claim_first_cleared()
+				{
+					v.push(p);
+					thread::yield_now();
+				}
+				v
+			})).collect();
+			handles.into_iter().map(|h| h.join().unwrap()).collect()
+		});
+		let mut all:Vec<usize>=claimed.into_iter().flatten().collect();
+		all.sort();
+		assert_eq!(all,(0..300).collect::<Vec<usize>>());
+		assert_eq!(bmp.count_ones(),300);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test] fn serde()
+	{
+		use serde_test::{Token, assert_de_tokens, assert_de_tokens_error, assert_tokens};
+		let mut bmp:StaticBitmap<12,{bitmap_words(12)}>=StaticBitmap::new();
+		bmp.set(0).unwrap();
+		bmp.set(9).unwrap();
+		bmp.set(11).unwrap();
+		assert_tokens(&bmp,&[Token::Seq{len:Some(2)},Token::U8(0x01),Token::U8(0x0A),Token::SeqEnd]);
+		assert_de_tokens(&bmp,&[Token::Bytes(&[0x01,0x0A])]);
+		assert_de_tokens_error::<StaticBitmap<12,{bitmap_words(12)}>>(&[Token::Seq{len:Some(1)},Token::U8(0x01),Token::SeqEnd],"invalid length 1, expected a sequence of 2 bytes without bits beyond 12");
+		assert_de_tokens_error::<StaticBitmap<12,{bitmap_words(12)}>>(&[Token::Bytes(&[0x01,0x0A,0x00])],"invalid length 3, expected a sequence of 2 bytes without bits beyond 12");
+		assert_de_tokens_error::<StaticBitmap<12,{bitmap_words(12)}>>(&[Token::Seq{len:Some(2)},Token::U8(0x01),Token::U8(0x1A)],"invalid value: integer `26`, expected a sequence of 2 bytes without bits beyond 12");
+		// The byte layout does not depend on the width of words.
+		let mut bmp:StaticBitmap<80,{bitmap_words(80)}>=StaticBitmap::new();
+		bmp.set(70).unwrap();
+		let mut tokens=std::vec![Token::Seq{len:Some(10)}];
+		tokens.extend((0..10).map(|i| Token::U8(if i==8 {0x40} else {0})));
+		tokens.push(Token::SeqEnd);
+		assert_tokens(&bmp,&tokens);
+	}
+}
@@ -0,0 +1,809 @@
+// The static-btree-map module
+
+use core::{borrow::Borrow, cmp::Ordering, fmt, iter::FusedIterator, mem, ops::{Bound, Index, RangeBounds}, slice};
+
+use crate::vec::{self, CapacityError, StaticVec};
+
+/// The `StaticBTreeMap` type is a fixed-capacity ordered map which can hold `N` key-value pairs at most. \
+/// Key-value pairs are kept in a `StaticVec` sorted by key, so lookup is a binary search.
+/// Despite the name, there is no tree: with a small and fixed `N`, a sorted array is both smaller and faster.
+/// 
+/// # Example
+/// ```
+/// use static_collections::btree_map::StaticBTreeMap;
+/// let mut m:StaticBTreeMap<8,u32,&str>=StaticBTreeMap::new();
+/// m.insert(3,"c").unwrap();
+/// m.insert(1,"a").unwrap();
+/// m.insert(2,"b").unwrap();
+/// assert!(m.keys().eq([1,2,3].iter()));
+/// assert_eq!(m.first_key_value(),Some((&1,&"a")));
+/// assert!(m.range(2..).map(|(_,v)| *v).eq(["b","c"]));
+/// ```
+pub struct StaticBTreeMap<const N:usize,K,V>
+{
+	entries:StaticVec<N,(K,V)>
+}
+
+impl<const N:usize,K,V> Default for StaticBTreeMap<N,K,V>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,K,V> StaticBTreeMap<N,K,V>
+{
+	/// Constructs a new, empty `StaticBTreeMap`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_map::StaticBTreeMap;
+	/// static M:StaticBTreeMap<8,u32,u32>=StaticBTreeMap::new();
+	/// assert!(M.is_empty());
+	/// ```
+	pub const fn new()->Self
+	{
+		Self
+		{
+			entries:StaticVec::new()
+		}
+	}
+
+	/// Returns the number of key-value pairs in the static-btree-map.
+	pub const fn len(&self)->usize
+	{
+		self.entries.len()
+	}
+
+	/// Checks if the static-btree-map is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.entries.len()==0
+	}
+
+	/// Checks if the static-btree-map is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.entries.len()==N
+	}
+
+	/// Returns the number of key-value pairs the static-btree-map can hold.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Returns all key-value pairs as a slice sorted by key.
+	pub const fn as_slice(&self)->&[(K,V)]
+	{
+		self.entries.as_slice()
+	}
+
+	/// Removes all key-value pairs from the static-btree-map.
+	pub fn clear(&mut self)
+	{
+		self.entries.clear();
+	}
+
+	/// Returns an iterator of all key-value pairs, sorted by key.
+	pub fn iter(&self)->Iter<'_,K,V>
+	{
+		Iter{inner:self.entries.iter()}
+	}
+
+	/// Returns an iterator of all key-value pairs, sorted by key, with mutable references to the values.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_map::StaticBTreeMap;
+	/// let mut m:StaticBTreeMap<8,u32,u32>=[(1,10),(2,20)].into_iter().collect();
+	/// m.iter_mut().for_each(|(k,v)| *v+=k);
+	/// assert_eq!(m.as_slice(),&[(1,11),(2,22)]);
+	/// ```
+	pub fn iter_mut(&mut self)->IterMut<'_,K,V>
+	{
+		IterMut{inner:self.entries.iter_mut()}
+	}
+
+	/// Returns an iterator of all keys in ascending order.
+	pub fn keys(&self)->Keys<'_,K,V>
+	{
+		Keys{inner:self.iter()}
+	}
+
+	/// Returns an iterator of all values, sorted by key.
+	pub fn values(&self)->Values<'_,K,V>
+	{
+		Values{inner:self.iter()}
+	}
+
+	/// Returns an iterator of mutable references to all values, sorted by key.
+	pub fn values_mut(&mut self)->ValuesMut<'_,K,V>
+	{
+		ValuesMut{inner:self.iter_mut()}
+	}
+
+	/// Returns the key-value pair with the minimum key.
+	pub fn first_key_value(&self)->Option<(&K,&V)>
+	{
+		self.entries.first().map(|(k,v)| (k,v))
+	}
+
+	/// Returns the key-value pair with the maximum key.
+	pub fn last_key_value(&self)->Option<(&K,&V)>
+	{
+		self.entries.last().map(|(k,v)| (k,v))
+	}
+
+	/// Removes and returns the key-value pair with the minimum key.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_map::StaticBTreeMap;
+	/// let mut m:StaticBTreeMap<8,u32,u32>=[(2,20),(1,10)].into_iter().collect();
+	/// assert_eq!(m.pop_first(),Some((1,10)));
+	/// assert_eq!(m.pop_first(),Some((2,20)));
+	/// assert_eq!(m.pop_first(),None);
+	/// ```
+	pub fn pop_first(&mut self)->Option<(K,V)>
+	{
+		self.entries.remove(0)
+	}
+
+	/// Removes and returns the key-value pair with the maximum key.
+	pub fn pop_last(&mut self)->Option<(K,V)>
+	{
+		self.entries.pop()
+	}
+
+	/// Retains only the key-value pairs specified by the predicate. \
+	/// In other words, remove all pairs `(k,v)` such that `f(&k,&mut v)` returns `false`.
+	/// The pairs are visited in ascending order.
+	pub fn retain<F:FnMut(&K,&mut V)->bool>(&mut self,mut f:F)
+	{
+		self.entries.retain_mut(|(k,v)| f(k,v));
+	}
+}
+
+impl<const N:usize,K:Ord,V> StaticBTreeMap<N,K,V>
+{
+	fn search<Q:?Sized+Ord>(&self,key:&Q)->Result<usize,usize> where K:Borrow<Q>
+	{
+		self.entries.binary_search_by(|(k,_)| k.borrow().cmp(key))
+	}
+
+	// Finds the index of the first key which is not on the left side of `bound`.
+	fn lower_index<Q:?Sized+Ord>(&self,bound:Bound<&Q>)->usize where K:Borrow<Q>
+	{
+		match bound
+		{
+			Bound::Included(q)=>self.entries.partition_point(|(k,_)| k.borrow()<q),
+			Bound::Excluded(q)=>self.entries.partition_point(|(k,_)| k.borrow()<=q),
+			Bound::Unbounded=>0
+		}
+	}
+
+	// Finds the index after the last key which is not on the right side of `bound`.
+	fn upper_index<Q:?Sized+Ord>(&self,bound:Bound<&Q>)->usize where K:Borrow<Q>
+	{
+		match bound
+		{
+			Bound::Included(q)=>self.entries.partition_point(|(k,_)| k.borrow()<=q),
+			Bound::Excluded(q)=>self.entries.partition_point(|(k,_)| k.borrow()<q),
+			Bound::Unbounded=>self.entries.len()
+		}
+	}
+
+	fn range_indices<Q:?Sized+Ord,R:RangeBounds<Q>>(&self,range:R)->(usize,usize) where K:Borrow<Q>
+	{
+		let (start,end)=(range.start_bound(),range.end_bound());
+		match (start,end)
+		{
+			(Bound::Excluded(s),Bound::Excluded(e)) if s==e=>panic!("Range starts and ends at the same excluded bound!"),
+			(Bound::Included(s)|Bound::Excluded(s),Bound::Included(e)|Bound::Excluded(e)) if s>e=>panic!("Range starts after its end!"),
+			_=>{}
+		}
+		let lo=self.lower_index(start);
+		let hi=self.upper_index(end);
+		(lo,hi.max(lo))
+	}
+
+	/// Returns a reference to the value corresponding to the key.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_map::StaticBTreeMap;
+	/// let mut m:StaticBTreeMap<8,u32,&str>=StaticBTreeMap::new();
+	/// m.insert(1,"a").unwrap();
+	/// assert_eq!(m.get(&1),Some(&"a"));
+	/// assert_eq!(m.get(&2),None);
+	/// ```
+	pub fn get<Q:?Sized+Ord>(&self,key:&Q)->Option<&V> where K:Borrow<Q>
+	{
+		self.get_key_value(key).map(|(_,v)| v)
+	}
+
+	/// Returns the key-value pair corresponding to the supplied key.
+	pub fn get_key_value<Q:?Sized+Ord>(&self,key:&Q)->Option<(&K,&V)> where K:Borrow<Q>
+	{
+		let i=self.search(key).ok()?;
+		let (k,v)=&self.entries[i];
+		Some((k,v))
+	}
+
+	/// Returns a mutable reference to the value corresponding to the key.
+	pub fn get_mut<Q:?Sized+Ord>(&mut self,key:&Q)->Option<&mut V> where K:Borrow<Q>
+	{
+		let i=self.search(key).ok()?;
+		Some(&mut self.entries[i].1)
+	}
+
+	/// Checks if the static-btree-map contains a value for the specified key.
+	pub fn contains_key<Q:?Sized+Ord>(&self,key:&Q)->bool where K:Borrow<Q>
+	{
+		self.search(key).is_ok()
+	}
+
+	/// Inserts a key-value pair into the static-btree-map. \
+	/// Returns `Ok(None)` if the key did not exist. \
+	/// Returns `Ok(Some(V))` with the old value if the key existed. In this case, the key is not updated. \
+	/// Returns `Err(CapacityError<(K,V)>)` with the key-value pair if the key did not exist and the static-btree-map is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_map::StaticBTreeMap;
+	/// let mut m:StaticBTreeMap<1,u32,u32>=StaticBTreeMap::new();
+	/// assert_eq!(m.insert(1,10),Ok(None));
+	/// assert_eq!(m.insert(1,11),Ok(Some(10)));
+	/// assert_eq!(m.insert(2,20).unwrap_err().into_inner(),(2,20));
+	/// ```
+	pub fn insert(&mut self,key:K,value:V)->Result<Option<V>,CapacityError<(K,V)>>
+	{
+		match self.search(&key)
+		{
+			Ok(i)=>Ok(Some(mem::replace(&mut self.entries[i].1,value))),
+			Err(i)=>self.entries.try_insert(i,(key,value)).map(|_| None)
+		}
+	}
+
+	/// Removes a key from the static-btree-map, returning the value at the key if the key existed.
+	pub fn remove<Q:?Sized+Ord>(&mut self,key:&Q)->Option<V> where K:Borrow<Q>
+	{
+		self.remove_entry(key).map(|(_,v)| v)
+	}
+
+	/// Removes a key from the static-btree-map, returning the stored key and value if the key existed.
+	pub fn remove_entry<Q:?Sized+Ord>(&mut self,key:&Q)->Option<(K,V)> where K:Borrow<Q>
+	{
+		let i=self.search(key).ok()?;
+		self.entries.remove(i)
+	}
+
+	/// Returns an iterator of the key-value pairs within the `range`, sorted by key.
+	/// 
+	/// # Panic
+	/// If the range starts after its end, or if the range starts and ends at the same excluded bound, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_map::StaticBTreeMap;
+	/// let m:StaticBTreeMap<8,u32,u32>=[(1,10),(3,30),(5,50),(7,70)].into_iter().collect();
+	/// assert!(m.range(2..=5).map(|(k,_)| *k).eq([3,5]));
+	/// assert!(m.range(..4).rev().map(|(k,_)| *k).eq([3,1]));
+	/// ```
+	pub fn range<Q:?Sized+Ord,R:RangeBounds<Q>>(&self,range:R)->Iter<'_,K,V> where K:Borrow<Q>
+	{
+		let (lo,hi)=self.range_indices(range);
+		Iter{inner:self.entries[lo..hi].iter()}
+	}
+
+	/// Returns an iterator of the key-value pairs within the `range`, sorted by key, with mutable references to the values.
+	/// 
+	/// # Panic
+	/// If the range starts after its end, or if the range starts and ends at the same excluded bound, this method would panic.
+	pub fn range_mut<Q:?Sized+Ord,R:RangeBounds<Q>>(&mut self,range:R)->IterMut<'_,K,V> where K:Borrow<Q>
+	{
+		let (lo,hi)=self.range_indices(range);
+		IterMut{inner:self.entries[lo..hi].iter_mut()}
+	}
+
+	/// Gets the entry of the specified key for in-place manipulation. \
+	/// Returns `Err(CapacityError<K>)` with the key if the key does not exist and the static-btree-map is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_map::StaticBTreeMap;
+	/// let mut m:StaticBTreeMap<8,char,u32>=StaticBTreeMap::new();
+	/// for c in "hello".chars()
+	/// {
+	///     *m.entry(c).unwrap().or_insert(0)+=1;
+	/// }
+	/// assert_eq!(m.as_slice(),&[('e',1),('h',1),('l',2),('o',1)]);
+	/// ```
+	pub fn entry(&mut self,key:K)->Result<Entry<'_,N,K,V>,CapacityError<K>>
+	{
+		match self.search(&key)
+		{
+			Ok(index)=>Ok(Entry::Occupied(OccupiedEntry{map:self,index})),
+			Err(_) if self.is_full()=>Err(CapacityError::new(key)),
+			Err(index)=>Ok(Entry::Vacant(VacantEntry{map:self,key,index}))
+		}
+	}
+}
+
+impl<const N:usize,K:Clone,V:Clone> Clone for StaticBTreeMap<N,K,V>
+{
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			entries:self.entries.iter().cloned().collect()
+		}
+	}
+}
+
+impl<const N:usize,K:fmt::Debug,V:fmt::Debug> fmt::Debug for StaticBTreeMap<N,K,V>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<const N:usize,K:PartialEq,V:PartialEq> PartialEq for StaticBTreeMap<N,K,V>
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.as_slice()==other.as_slice()
+	}
+}
+
+impl<const N:usize,K:Eq,V:Eq> Eq for StaticBTreeMap<N,K,V> {}
+
+impl<const N:usize,K:PartialOrd,V:PartialOrd> PartialOrd for StaticBTreeMap<N,K,V>
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		self.as_slice().partial_cmp(other.as_slice())
+	}
+}
+
+impl<const N:usize,K:Ord,V:Ord> Ord for StaticBTreeMap<N,K,V>
+{
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.as_slice().cmp(other.as_slice())
+	}
+}
+
+impl<const N:usize,K:Ord+Borrow<Q>,Q:?Sized+Ord,V> Index<&Q> for StaticBTreeMap<N,K,V>
+{
+	type Output = V;
+
+	fn index(&self, key: &Q) -> &Self::Output
+	{
+		match self.get(key)
+		{
+			Some(v)=>v,
+			None=>panic!("Key is not found in StaticBTreeMap!")
+		}
+	}
+}
+
+impl<const N:usize,K:Ord,V> Extend<(K,V)> for StaticBTreeMap<N,K,V>
+{
+	fn extend<I: IntoIterator<Item = (K,V)>>(&mut self, iter: I)
+	{
+		for (k,v) in iter
+		{
+			if self.insert(k,v).is_err()
+			{
+				panic!("StaticBTreeMap buffer overflow!");
+			}
+		}
+	}
+}
+
+impl<const N:usize,K:Ord,V> FromIterator<(K,V)> for StaticBTreeMap<N,K,V>
+{
+	fn from_iter<I: IntoIterator<Item = (K,V)>>(iter: I) -> Self
+	{
+		let mut m=Self::new();
+		m.extend(iter);
+		m
+	}
+}
+
+impl<const N:usize,K,V> IntoIterator for StaticBTreeMap<N,K,V>
+{
+	type Item = (K,V);
+	type IntoIter = vec::IntoIter<N,(K,V)>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.entries.into_iter()
+	}
+}
+
+impl<'a,const N:usize,K,V> IntoIterator for &'a StaticBTreeMap<N,K,V>
+{
+	type Item = (&'a K,&'a V);
+	type IntoIter = Iter<'a,K,V>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a,const N:usize,K,V> IntoIterator for &'a mut StaticBTreeMap<N,K,V>
+{
+	type Item = (&'a K,&'a mut V);
+	type IntoIter = IterMut<'a,K,V>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+/// A view into a single entry in a static-btree-map, which may either be vacant or occupied. \
+/// This enum is constructed from the `entry` method of `StaticBTreeMap`.
+pub enum Entry<'a,const N:usize,K,V>
+{
+	/// An occupied entry.
+	Occupied(OccupiedEntry<'a,N,K,V>),
+	/// A vacant entry.
+	Vacant(VacantEntry<'a,N,K,V>)
+}
+
+impl<'a,const N:usize,K,V> Entry<'a,N,K,V>
+{
+	/// Ensures a value is in the entry by inserting `default` if empty. \
+	/// Returns a mutable reference to the value in the entry.
+	pub fn or_insert(self,default:V)->&'a mut V
+	{
+		match self
+		{
+			Self::Occupied(e)=>e.into_mut(),
+			Self::Vacant(e)=>e.insert(default)
+		}
+	}
+
+	/// Ensures a value is in the entry by inserting the result of `default` if empty. \
+	/// Returns a mutable reference to the value in the entry.
+	pub fn or_insert_with<F:FnOnce()->V>(self,default:F)->&'a mut V
+	{
+		match self
+		{
+			Self::Occupied(e)=>e.into_mut(),
+			Self::Vacant(e)=>e.insert(default())
+		}
+	}
+
+	/// Ensures a value is in the entry by inserting the default value if empty. \
+	/// Returns a mutable reference to the value in the entry.
+	pub fn or_default(self)->&'a mut V where V:Default
+	{
+		self.or_insert_with(V::default)
+	}
+
+	/// Provides in-place mutable access to an occupied entry before any potential inserts into the map.
+	pub fn and_modify<F:FnOnce(&mut V)>(mut self,f:F)->Self
+	{
+		if let Self::Occupied(e)=&mut self
+		{
+			f(e.get_mut());
+		}
+		self
+	}
+
+	/// Returns a reference to the key of this entry.
+	pub fn key(&self)->&K
+	{
+		match self
+		{
+			Self::Occupied(e)=>e.key(),
+			Self::Vacant(e)=>e.key()
+		}
+	}
+}
+
+/// A view into an occupied entry in a static-btree-map. It is part of the `Entry` enum.
+pub struct OccupiedEntry<'a,const N:usize,K,V>
+{
+	map:&'a mut StaticBTreeMap<N,K,V>,
+	index:usize
+}
+
+impl<'a,const N:usize,K,V> OccupiedEntry<'a,N,K,V>
+{
+	/// Returns a reference to the key in the entry.
+	pub fn key(&self)->&K
+	{
+		&self.map.entries[self.index].0
+	}
+
+	/// Returns a reference to the value in the entry.
+	pub fn get(&self)->&V
+	{
+		&self.map.entries[self.index].1
+	}
+
+	/// Returns a mutable reference to the value in the entry.
+	pub fn get_mut(&mut self)->&mut V
+	{
+		&mut self.map.entries[self.index].1
+	}
+
+	/// Converts the entry into a mutable reference to the value with the lifetime of the map.
+	pub fn into_mut(self)->&'a mut V
+	{
+		&mut self.map.entries[self.index].1
+	}
+
+	/// Sets the value of the entry, and returns the old value.
+	pub fn insert(&mut self,value:V)->V
+	{
+		mem::replace(self.get_mut(),value)
+	}
+
+	/// Takes the value out of the entry, and returns it.
+	pub fn remove(self)->V
+	{
+		self.remove_entry().1
+	}
+
+	/// Takes the key-value pair out of the entry, and returns it.
+	pub fn remove_entry(self)->(K,V)
+	{
+		self.map.entries.remove(self.index).unwrap()
+	}
+}
+
+/// A view into a vacant entry in a static-btree-map. It is part of the `Entry` enum.
+pub struct VacantEntry<'a,const N:usize,K,V>
+{
+	map:&'a mut StaticBTreeMap<N,K,V>,
+	key:K,
+	index:usize
+}
+
+impl<'a,const N:usize,K,V> VacantEntry<'a,N,K,V>
+{
+	/// Returns a reference to the key that would be used when inserting a value through the entry.
+	pub fn key(&self)->&K
+	{
+		&self.key
+	}
+
+	/// Takes the ownership of the key.
+	pub fn into_key(self)->K
+	{
+		self.key
+	}
+
+	/// Sets the value of the entry with the key of this entry, and returns a mutable reference to it.
+	pub fn insert(self,value:V)->&'a mut V
+	{
+		// The capacity is checked when the entry is created.
+		self.map.entries.insert(self.index,(self.key,value));
+		&mut self.map.entries[self.index].1
+	}
+}
+
+/// Immutable static-btree-map iterator.
+pub struct Iter<'a,K,V>
+{
+	inner:slice::Iter<'a,(K,V)>
+}
+
+impl<'a,K,V> Iterator for Iter<'a,K,V>
+{
+	type Item = (&'a K,&'a V);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(k,v)| (k,v))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,K,V> DoubleEndedIterator for Iter<'a,K,V>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back().map(|(k,v)| (k,v))
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for Iter<'a,K,V> {}
+impl<'a,K,V> FusedIterator for Iter<'a,K,V> {}
+
+impl<'a,K,V> Clone for Iter<'a,K,V>
+{
+	fn clone(&self) -> Self
+	{
+		Self{inner:self.inner.clone()}
+	}
+}
+
+/// Mutable static-btree-map iterator.
+pub struct IterMut<'a,K,V>
+{
+	inner:slice::IterMut<'a,(K,V)>
+}
+
+impl<'a,K,V> Iterator for IterMut<'a,K,V>
+{
+	type Item = (&'a K,&'a mut V);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(k,v)| (&*k,v))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,K,V> DoubleEndedIterator for IterMut<'a,K,V>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back().map(|(k,v)| (&*k,v))
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for IterMut<'a,K,V> {}
+impl<'a,K,V> FusedIterator for IterMut<'a,K,V> {}
+
+/// An iterator of the keys of a static-btree-map in ascending order.
+pub struct Keys<'a,K,V>
+{
+	inner:Iter<'a,K,V>
+}
+
+impl<'a,K,V> Iterator for Keys<'a,K,V>
+{
+	type Item = &'a K;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(k,_)| k)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,K,V> DoubleEndedIterator for Keys<'a,K,V>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back().map(|(k,_)| k)
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for Keys<'a,K,V> {}
+impl<'a,K,V> FusedIterator for Keys<'a,K,V> {}
+
+/// An iterator of the values of a static-btree-map, sorted by key.
+pub struct Values<'a,K,V>
+{
+	inner:Iter<'a,K,V>
+}
+
+impl<'a,K,V> Iterator for Values<'a,K,V>
+{
+	type Item = &'a V;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(_,v)| v)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,K,V> DoubleEndedIterator for Values<'a,K,V>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back().map(|(_,v)| v)
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for Values<'a,K,V> {}
+impl<'a,K,V> FusedIterator for Values<'a,K,V> {}
+
+/// A mutable iterator of the values of a static-btree-map, sorted by key.
+pub struct ValuesMut<'a,K,V>
+{
+	inner:IterMut<'a,K,V>
+}
+
+impl<'a,K,V> Iterator for ValuesMut<'a,K,V>
+{
+	type Item = &'a mut V;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(_,v)| v)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,K,V> DoubleEndedIterator for ValuesMut<'a,K,V>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back().map(|(_,v)| v)
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for ValuesMut<'a,K,V> {}
+impl<'a,K,V> FusedIterator for ValuesMut<'a,K,V> {}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::{collections::BTreeMap, vec::Vec};
+	use crate::{btree_map::StaticBTreeMap, xorshift};
+
+	#[test] fn against_std()
+	{
+		let mut state:u64=0x9E37_79B9_7F4A_7C15;
+		let mut m:StaticBTreeMap<24,u32,u32>=StaticBTreeMap::new();
+		let mut model:BTreeMap<u32,u32>=BTreeMap::new();
+		for _ in 0..10000
+		{
+			let r=xorshift(&mut state);
+			let k=(r%40) as u32;
+			let v=(r>>32) as u32;
+			match (r>>8)%3
+			{
+				0=>
+				{
+					let full=model.len()==24 && !model.contains_key(&k);
+					match m.insert(k,v)
+					{
+						Ok(old)=>assert_eq!(old,model.insert(k,v)),
+						Err(e)=>
+						{
+							assert!(full);
+							assert_eq!(e.into_inner(),(k,v));
+						}
+					}
+				}
+				1=>assert_eq!(m.remove(&k),model.remove(&k)),
+				_=>
+				{
+					let hi=k+(v%10);
+					assert!(m.range(k..hi).eq(model.range(k..hi)));
+					assert!(m.range(..=k).rev().eq(model.range(..=k).rev()));
+				}
+			}
+			assert!(m.iter().eq(model.iter()));
+		}
+		let firsts:Vec<(u32,u32)>=core::iter::from_fn(|| m.pop_first()).collect();
+		assert!(firsts.into_iter().eq(model.into_iter()));
+	}
+}
@@ -0,0 +1,638 @@
+// The static-btree-set module
+
+use core::{borrow::Borrow, cmp::Ordering, fmt, iter::{FusedIterator, Peekable}, ops::RangeBounds};
+
+use crate::{btree_map::{self, StaticBTreeMap}, vec::{self, CapacityError, StaticVec}};
+
+/// The `StaticBTreeSet` type is a fixed-capacity ordered set which can hold `N` values at most. \
+/// It is implemented as a `StaticBTreeMap` whose values are `()`, so values are kept sorted.
+/// 
+/// # Example
+/// ```
+/// use static_collections::btree_set::StaticBTreeSet;
+/// let mut s:StaticBTreeSet<8,u32>=StaticBTreeSet::new();
+/// assert_eq!(s.insert(3),Ok(true));
+/// assert_eq!(s.insert(1),Ok(true));
+/// assert_eq!(s.insert(3),Ok(false));
+/// assert!(s.iter().eq([1,3].iter()));
+/// ```
+pub struct StaticBTreeSet<const N:usize,T>
+{
+	map:StaticBTreeMap<N,T,()>
+}
+
+impl<const N:usize,T> Default for StaticBTreeSet<N,T>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,T> StaticBTreeSet<N,T>
+{
+	/// Constructs a new, empty `StaticBTreeSet`.
+	pub const fn new()->Self
+	{
+		Self
+		{
+			map:StaticBTreeMap::new()
+		}
+	}
+
+	/// Returns the number of values in the static-btree-set.
+	pub const fn len(&self)->usize
+	{
+		self.map.len()
+	}
+
+	/// Checks if the static-btree-set is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.map.is_empty()
+	}
+
+	/// Checks if the static-btree-set is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.map.is_full()
+	}
+
+	/// Returns the number of values the static-btree-set can hold.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Returns an iterator of all values in ascending order.
+	pub fn iter(&self)->Iter<'_,T>
+	{
+		Iter{inner:self.map.keys()}
+	}
+
+	/// Returns the minimum value in the static-btree-set.
+	pub fn first(&self)->Option<&T>
+	{
+		self.map.first_key_value().map(|(k,_)| k)
+	}
+
+	/// Returns the maximum value in the static-btree-set.
+	pub fn last(&self)->Option<&T>
+	{
+		self.map.last_key_value().map(|(k,_)| k)
+	}
+
+	/// Removes and returns the minimum value in the static-btree-set.
+	pub fn pop_first(&mut self)->Option<T>
+	{
+		self.map.pop_first().map(|(k,_)| k)
+	}
+
+	/// Removes and returns the maximum value in the static-btree-set.
+	pub fn pop_last(&mut self)->Option<T>
+	{
+		self.map.pop_last().map(|(k,_)| k)
+	}
+
+	/// Retains only the values specified by the predicate. \
+	/// In other words, remove all values `v` such that `f(&v)` returns `false`.
+	/// The values are visited in ascending order.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_set::StaticBTreeSet;
+	/// let mut s:StaticBTreeSet<8,u32>=(1..=6).collect();
+	/// s.retain(|v| v%2==0);
+	/// assert!(s.iter().eq([2,4,6].iter()));
+	/// ```
+	pub fn retain<F:FnMut(&T)->bool>(&mut self,mut f:F)
+	{
+		self.map.retain(|k,_| f(k));
+	}
+
+	/// Removes all values from the static-btree-set.
+	pub fn clear(&mut self)
+	{
+		self.map.clear();
+	}
+}
+
+impl<const N:usize,T:Ord> StaticBTreeSet<N,T>
+{
+	/// Checks if the static-btree-set contains the value.
+	pub fn contains<Q:?Sized+Ord>(&self,value:&Q)->bool where T:Borrow<Q>
+	{
+		self.map.contains_key(value)
+	}
+
+	/// Returns a reference to the value in the static-btree-set that is equal to the given value.
+	pub fn get<Q:?Sized+Ord>(&self,value:&Q)->Option<&T> where T:Borrow<Q>
+	{
+		self.map.get_key_value(value).map(|(k,_)| k)
+	}
+
+	/// Adds a value to the static-btree-set. \
+	/// Returns `Ok(true)` if the value did not exist. \
+	/// Returns `Ok(false)` if the value existed. In this case, the set is not modified. \
+	/// Returns `Err(CapacityError<T>)` with the value if the value did not exist and the static-btree-set is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_set::StaticBTreeSet;
+	/// let mut s:StaticBTreeSet<1,u32>=StaticBTreeSet::new();
+	/// assert_eq!(s.insert(1),Ok(true));
+	/// assert_eq!(s.insert(1),Ok(false));
+	/// assert_eq!(s.insert(2).unwrap_err().into_inner(),2);
+	/// ```
+	pub fn insert(&mut self,value:T)->Result<bool,CapacityError<T>>
+	{
+		match self.map.insert(value,())
+		{
+			Ok(old)=>Ok(old.is_none()),
+			Err(e)=>Err(CapacityError::new(e.into_inner().0))
+		}
+	}
+
+	/// Removes a value from the static-btree-set. Returns whether the value existed.
+	pub fn remove<Q:?Sized+Ord>(&mut self,value:&Q)->bool where T:Borrow<Q>
+	{
+		self.map.remove(value).is_some()
+	}
+
+	/// Removes and returns the value in the static-btree-set that is equal to the given value.
+	pub fn take<Q:?Sized+Ord>(&mut self,value:&Q)->Option<T> where T:Borrow<Q>
+	{
+		self.map.remove_entry(value).map(|(k,_)| k)
+	}
+
+	/// Returns an iterator of the values within the `range` in ascending order.
+	/// 
+	/// # Panic
+	/// If the range starts after its end, or if the range starts and ends at the same excluded bound, this method would panic.
+	pub fn range<Q:?Sized+Ord,R:RangeBounds<Q>>(&self,range:R)->Range<'_,T> where T:Borrow<Q>
+	{
+		Range{inner:self.map.range(range)}
+	}
+
+	/// Returns an iterator of the values in `self` but not in `other`, in ascending order.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_set::StaticBTreeSet;
+	/// let a:StaticBTreeSet<8,u32>=[1,2,3].into_iter().collect();
+	/// let b:StaticBTreeSet<8,u32>=[2,3,4].into_iter().collect();
+	/// assert!(a.difference(&b).eq([1].iter()));
+	/// ```
+	pub fn difference<'a>(&'a self,other:&'a Self)->Difference<'a,T>
+	{
+		Difference{inner:Merge::new(self,other)}
+	}
+
+	/// Returns an iterator of the values in `self` or in `other`, but not in both, in ascending order.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_set::StaticBTreeSet;
+	/// let a:StaticBTreeSet<8,u32>=[1,2,3].into_iter().collect();
+	/// let b:StaticBTreeSet<8,u32>=[2,3,4].into_iter().collect();
+	/// assert!(a.symmetric_difference(&b).eq([1,4].iter()));
+	/// ```
+	pub fn symmetric_difference<'a>(&'a self,other:&'a Self)->SymmetricDifference<'a,T>
+	{
+		SymmetricDifference{inner:Merge::new(self,other)}
+	}
+
+	/// Returns an iterator of the values in both `self` and `other`, in ascending order.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_set::StaticBTreeSet;
+	/// let a:StaticBTreeSet<8,u32>=[1,2,3].into_iter().collect();
+	/// let b:StaticBTreeSet<8,u32>=[2,3,4].into_iter().collect();
+	/// assert!(a.intersection(&b).eq([2,3].iter()));
+	/// ```
+	pub fn intersection<'a>(&'a self,other:&'a Self)->Intersection<'a,T>
+	{
+		Intersection{inner:Merge::new(self,other)}
+	}
+
+	/// Returns an iterator of the values in `self` or in `other`, without duplicates, in ascending order.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::btree_set::StaticBTreeSet;
+	/// let a:StaticBTreeSet<8,u32>=[1,2,3].into_iter().collect();
+	/// let b:StaticBTreeSet<8,u32>=[2,3,4].into_iter().collect();
+	/// assert!(a.union(&b).eq([1,2,3,4].iter()));
+	/// ```
+	pub fn union<'a>(&'a self,other:&'a Self)->Union<'a,T>
+	{
+		Union{inner:Merge::new(self,other)}
+	}
+
+	/// Checks if `self` has no values in common with `other`.
+	pub fn is_disjoint(&self,other:&Self)->bool
+	{
+		self.intersection(other).next().is_none()
+	}
+
+	/// Checks if all values of `self` are in `other`.
+	pub fn is_subset(&self,other:&Self)->bool
+	{
+		self.len()<=other.len() && self.difference(other).next().is_none()
+	}
+
+	/// Checks if all values of `other` are in `self`.
+	pub fn is_superset(&self,other:&Self)->bool
+	{
+		other.is_subset(self)
+	}
+}
+
+impl<const N:usize,T:Clone> Clone for StaticBTreeSet<N,T>
+{
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			map:self.map.clone()
+		}
+	}
+}
+
+impl<const N:usize,T:fmt::Debug> fmt::Debug for StaticBTreeSet<N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<const N:usize,T:PartialEq> PartialEq for StaticBTreeSet<N,T>
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.map==other.map
+	}
+}
+
+impl<const N:usize,T:Eq> Eq for StaticBTreeSet<N,T> {}
+
+impl<const N:usize,T:PartialOrd> PartialOrd for StaticBTreeSet<N,T>
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		self.map.partial_cmp(&other.map)
+	}
+}
+
+impl<const N:usize,T:Ord> Ord for StaticBTreeSet<N,T>
+{
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.map.cmp(&other.map)
+	}
+}
+
+impl<const N:usize,T:Ord> Extend<T> for StaticBTreeSet<N,T>
+{
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+	{
+		for v in iter
+		{
+			if self.insert(v).is_err()
+			{
+				panic!("StaticBTreeSet buffer overflow!");
+			}
+		}
+	}
+}
+
+impl<const N:usize,T:Ord> FromIterator<T> for StaticBTreeSet<N,T>
+{
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self
+	{
+		let mut s=Self::new();
+		s.extend(iter);
+		s
+	}
+}
+
+/// Collects the values of a `StaticVec` into a `StaticBTreeSet`. Duplicated values are dropped. \
+/// This conversion never overflows, because there are at most `N` distinct values.
+/// 
+/// # Example
+/// ```
+/// use static_collections::{btree_set::StaticBTreeSet,vec::StaticVec};
+/// let v:StaticVec<4,u32>=[3,1,3,2].into_iter().collect();
+/// let s:StaticBTreeSet<4,u32>=StaticBTreeSet::from(v);
+/// assert_eq!(StaticVec::from(s).as_slice(),&[1,2,3]);
+/// ```
+impl<const N:usize,T:Ord> From<StaticVec<N,T>> for StaticBTreeSet<N,T>
+{
+	fn from(value: StaticVec<N,T>) -> Self
+	{
+		value.into_iter().collect()
+	}
+}
+
+/// Moves the values of a `StaticBTreeSet` into a `StaticVec` in ascending order.
+impl<const N:usize,T> From<StaticBTreeSet<N,T>> for StaticVec<N,T>
+{
+	fn from(value: StaticBTreeSet<N,T>) -> Self
+	{
+		value.into_iter().collect()
+	}
+}
+
+impl<const N:usize,T> IntoIterator for StaticBTreeSet<N,T>
+{
+	type Item = T;
+	type IntoIter = IntoIter<N,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		IntoIter{inner:self.map.into_iter()}
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a StaticBTreeSet<N,T>
+{
+	type Item = &'a T;
+	type IntoIter = Iter<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+/// Immutable static-btree-set iterator.
+pub struct Iter<'a,T>
+{
+	inner:btree_map::Keys<'a,T,()>
+}
+
+impl<'a,T> Iterator for Iter<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,T> DoubleEndedIterator for Iter<'a,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back()
+	}
+}
+
+impl<'a,T> ExactSizeIterator for Iter<'a,T> {}
+impl<'a,T> FusedIterator for Iter<'a,T> {}
+
+/// An iterator of the values within a range of a static-btree-set. \
+/// This struct is created by the `range` method of `StaticBTreeSet`.
+pub struct Range<'a,T>
+{
+	inner:btree_map::Iter<'a,T,()>
+}
+
+impl<'a,T> Iterator for Range<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(k,_)| k)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,T> DoubleEndedIterator for Range<'a,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back().map(|(k,_)| k)
+	}
+}
+
+impl<'a,T> ExactSizeIterator for Range<'a,T> {}
+impl<'a,T> FusedIterator for Range<'a,T> {}
+
+/// Owning static-btree-set iterator.
+pub struct IntoIter<const N:usize,T>
+{
+	inner:vec::IntoIter<N,(T,())>
+}
+
+impl<const N:usize,T> Iterator for IntoIter<N,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(k,_)| k)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<const N:usize,T> DoubleEndedIterator for IntoIter<N,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back().map(|(k,_)| k)
+	}
+}
+
+impl<const N:usize,T> ExactSizeIterator for IntoIter<N,T> {}
+impl<const N:usize,T> FusedIterator for IntoIter<N,T> {}
+
+// Walks two sorted sets together. Each step yields the smaller value,
+// telling whether it is from the left set, the right set, or both.
+struct Merge<'a,T>
+{
+	a:Peekable<Iter<'a,T>>,
+	b:Peekable<Iter<'a,T>>
+}
+
+impl<'a,T:Ord> Merge<'a,T>
+{
+	fn new<const N:usize>(a:&'a StaticBTreeSet<N,T>,b:&'a StaticBTreeSet<N,T>)->Self
+	{
+		Self
+		{
+			a:a.iter().peekable(),
+			b:b.iter().peekable()
+		}
+	}
+
+	fn next(&mut self)->Option<(Option<&'a T>,Option<&'a T>)>
+	{
+		let order=match (self.a.peek(),self.b.peek())
+		{
+			(None,None)=>return None,
+			(Some(_),None)=>Ordering::Less,
+			(None,Some(_))=>Ordering::Greater,
+			(Some(x),Some(y))=>x.cmp(y)
+		};
+		Some(match order
+		{
+			Ordering::Less=>(self.a.next(),None),
+			Ordering::Greater=>(None,self.b.next()),
+			Ordering::Equal=>(self.a.next(),self.b.next())
+		})
+	}
+}
+
+/// A lazy iterator of the values in the intersection of two static-btree-sets, in ascending order.
+pub struct Intersection<'a,T>
+{
+	inner:Merge<'a,T>
+}
+
+impl<'a,T:Ord> Iterator for Intersection<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		loop
+		{
+			if let (Some(x),Some(_))=self.inner.next()?
+			{
+				return Some(x);
+			}
+		}
+	}
+}
+
+impl<'a,T:Ord> FusedIterator for Intersection<'a,T> {}
+
+/// A lazy iterator of the values in the difference of two static-btree-sets, in ascending order.
+pub struct Difference<'a,T>
+{
+	inner:Merge<'a,T>
+}
+
+impl<'a,T:Ord> Iterator for Difference<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		loop
+		{
+			// Nothing is left once the left set is exhausted.
+			self.inner.a.peek()?;
+			if let (Some(x),None)=self.inner.next()?
+			{
+				return Some(x);
+			}
+		}
+	}
+}
+
+impl<'a,T:Ord> FusedIterator for Difference<'a,T> {}
+
+/// A lazy iterator of the values in the symmetric difference of two static-btree-sets, in ascending order.
+pub struct SymmetricDifference<'a,T>
+{
+	inner:Merge<'a,T>
+}
+
+impl<'a,T:Ord> Iterator for SymmetricDifference<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		loop
+		{
+			match self.inner.next()?
+			{
+				(Some(x),None)|(None,Some(x))=>return Some(x),
+				_=>{}
+			}
+		}
+	}
+}
+
+impl<'a,T:Ord> FusedIterator for SymmetricDifference<'a,T> {}
+
+/// A lazy iterator of the values in the union of two static-btree-sets, in ascending order.
+pub struct Union<'a,T>
+{
+	inner:Merge<'a,T>
+}
+
+impl<'a,T:Ord> Iterator for Union<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let (x,y)=self.inner.next()?;
+		x.or(y)
+	}
+}
+
+impl<'a,T:Ord> FusedIterator for Union<'a,T> {}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::{collections::BTreeSet, vec::Vec};
+	use crate::{btree_set::StaticBTreeSet, hash_set::StaticHashSet, xorshift};
+
+	#[test] fn set_operations()
+	{
+		let mut state:u64=0x1234_5678_9ABC_DEF1;
+		for _ in 0..200
+		{
+			let mut sets:[(StaticBTreeSet<16,u32>,StaticHashSet<16,u32>,BTreeSet<u32>);2]=Default::default();
+			for (a,b,c) in sets.iter_mut()
+			{
+				for _ in 0..16
+				{
+					let r=xorshift(&mut state);
+					let v=(r%24) as u32;
+					a.insert(v).unwrap();
+					b.insert(v).unwrap();
+					c.insert(v);
+				}
+			}
+			let [(a1,h1,s1),(a2,h2,s2)]=&sets;
+			let sorted=|i:&mut dyn Iterator<Item=&u32>| {let mut v:Vec<u32>=i.copied().collect(); v.sort(); v};
+			assert!(a1.union(a2).eq(s1.union(s2)));
+			assert!(a1.intersection(a2).eq(s1.intersection(s2)));
+			assert!(a1.difference(a2).eq(s1.difference(s2)));
+			assert!(a1.symmetric_difference(a2).eq(s1.symmetric_difference(s2)));
+			assert_eq!(sorted(&mut h1.union(h2)),sorted(&mut s1.union(s2)));
+			assert_eq!(sorted(&mut h1.intersection(h2)),sorted(&mut s1.intersection(s2)));
+			assert_eq!(sorted(&mut h1.difference(h2)),sorted(&mut s1.difference(s2)));
+			assert_eq!(sorted(&mut h1.symmetric_difference(h2)),sorted(&mut s1.symmetric_difference(s2)));
+			assert_eq!(a1.is_subset(a2),s1.is_subset(s2));
+			assert_eq!(h1.is_subset(h2),s1.is_subset(s2));
+			assert_eq!(a1.is_disjoint(a2),s1.is_disjoint(s2));
+			assert_eq!(h1.is_disjoint(h2),s1.is_disjoint(s2));
+		}
+	}
+}
@@ -0,0 +1,1225 @@
+// The static-deque module
+
+use core::{fmt, iter::FusedIterator, mem::MaybeUninit, ops::{Index, IndexMut, RangeBounds}, ptr, slice};
+
+use crate::vec::{CapacityError, resolve_range};
+
+/// The `StaticDeque` type is a fixed-capacity double-ended queue implemented with a ring buffer.
+pub struct StaticDeque<const N:usize,T>
+{
+	head:usize,
+	length:usize,
+	buff:MaybeUninit<[T;N]>
+}
+
+impl<const N:usize,T> Default for StaticDeque<N,T>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,T> StaticDeque<N,T>
+{
+	/// Constructs a new, empty StaticDeque<N,T>.
+	/// 
+	/// The `new` method will not zero the buffer, so the initial operation is very inexpensive.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// ```
+	pub const fn new()->Self
+	{
+		Self
+		{
+			head:0,
+			length:0,
+			buff:MaybeUninit::uninit()
+		}
+	}
+
+	const fn buf_ptr(&self)->*const T
+	{
+		self.buff.as_ptr().cast()
+	}
+
+	const fn buf_mut_ptr(&mut self)->*mut T
+	{
+		self.buff.as_mut_ptr().cast()
+	}
+
+	// Translates a logical index into a physical index in the ring buffer.
+	// The `index` must not exceed `N`.
+	const fn physical(&self,index:usize)->usize
+	{
+		let i=self.head+index;
+		if i>=N {i-N} else {i}
+	}
+
+	/// Returns the number of values in the static-deque.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_back(1);
+	/// q.push_front(2);
+	/// assert_eq!(q.len(),2);
+	/// ```
+	pub const fn len(&self)->usize
+	{
+		self.length
+	}
+
+	/// Checks if the static-deque is empty.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// assert!(q.is_empty());
+	/// q.push_back(1);
+	/// assert!(!q.is_empty());
+	/// ```
+	pub const fn is_empty(&self)->bool
+	{
+		self.length==0
+	}
+
+	/// Checks if the static-deque is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<2,u64>=StaticDeque::new();
+	/// q.push_back(1);
+	/// q.push_back(2);
+	/// assert!(q.is_full());
+	/// ```
+	pub const fn is_full(&self)->bool
+	{
+		self.length==N
+	}
+
+	/// Returns the capacity of the static-deque.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let q:StaticDeque<12,u64>=StaticDeque::new();
+	/// assert_eq!(q.capacity(),12);
+	/// ```
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Put value `v` to the back of the static-deque.
+	/// 
+	/// # Panic
+	/// If the static-deque is full, this method would panic. \
+	/// Use `try_push_back` method if you would like to handle the overflow.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_back(1);
+	/// q.push_back(2);
+	/// assert_eq!(q.back(),Some(&2));
+	/// ```
+	pub const fn push_back(&mut self,v:T)
+	{
+		if self.length>=N
+		{
+			panic!("StaticDeque buffer overflow!");
+		}
+		let i=self.physical(self.length);
+		unsafe
+		{
+			// Use ptr::write to avoid `Drop` trait requirement.
+			ptr::write(self.buf_mut_ptr().add(i),v);
+		}
+		self.length+=1;
+	}
+
+	/// Put value `v` to the back of the static-deque. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static-deque is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<1,u64>=StaticDeque::new();
+	/// assert!(q.try_push_back(1).is_ok());
+	/// assert_eq!(q.try_push_back(2).unwrap_err().into_inner(),2);
+	/// ```
+	pub const fn try_push_back(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		if self.length<N
+		{
+			self.push_back(v);
+			Ok(())
+		}
+		else
+		{
+			Err(CapacityError::new(v))
+		}
+	}
+
+	/// Put value `v` to the front of the static-deque.
+	/// 
+	/// # Panic
+	/// If the static-deque is full, this method would panic. \
+	/// Use `try_push_front` method if you would like to handle the overflow.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_front(1);
+	/// q.push_front(2);
+	/// assert_eq!(q.front(),Some(&2));
+	/// ```
+	pub const fn push_front(&mut self,v:T)
+	{
+		if self.length>=N
+		{
+			panic!("StaticDeque buffer overflow!");
+		}
+		self.head=if self.head==0 {N-1} else {self.head-1};
+		unsafe
+		{
+			// Use ptr::write to avoid `Drop` trait requirement.
+			ptr::write(self.buf_mut_ptr().add(self.head),v);
+		}
+		self.length+=1;
+	}
+
+	/// Put value `v` to the front of the static-deque. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static-deque is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<1,u64>=StaticDeque::new();
+	/// assert!(q.try_push_front(1).is_ok());
+	/// assert_eq!(q.try_push_front(2).unwrap_err().into_inner(),2);
+	/// ```
+	pub const fn try_push_front(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		if self.length<N
+		{
+			self.push_front(v);
+			Ok(())
+		}
+		else
+		{
+			Err(CapacityError::new(v))
+		}
+	}
+
+	/// Read and remove the value at the back of the static-deque.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_back(1);
+	/// q.push_back(2);
+	/// assert_eq!(q.pop_back(),Some(2));
+	/// assert_eq!(q.pop_back(),Some(1));
+	/// assert_eq!(q.pop_back(),None);
+	/// ```
+	pub const fn pop_back(&mut self)->Option<T>
+	{
+		if self.length>0
+		{
+			self.length-=1;
+			let i=self.physical(self.length);
+			// Use unsafe codes to avoid `Copy` trait.
+			unsafe
+			{
+				Some(ptr::read(self.buf_ptr().add(i)))
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Read and remove the value at the front of the static-deque.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_back(1);
+	/// q.push_back(2);
+	/// assert_eq!(q.pop_front(),Some(1));
+	/// assert_eq!(q.pop_front(),Some(2));
+	/// assert_eq!(q.pop_front(),None);
+	/// ```
+	pub const fn pop_front(&mut self)->Option<T>
+	{
+		if self.length>0
+		{
+			let i=self.head;
+			self.head=self.physical(1);
+			self.length-=1;
+			// Use unsafe codes to avoid `Copy` trait.
+			unsafe
+			{
+				Some(ptr::read(self.buf_ptr().add(i)))
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Returns a reference to the value at location `index`, where `0` is the front of the static-deque.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_back(2);
+	/// q.push_front(1);
+	/// assert_eq!(q.get(1),Some(&2));
+	/// assert_eq!(q.get(2),None);
+	/// ```
+	pub fn get(&self,index:usize)->Option<&T>
+	{
+		if index<self.length
+		{
+			unsafe
+			{
+				Some(&*self.buf_ptr().add(self.physical(index)))
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Returns a mutable reference to the value at location `index`, where `0` is the front of the static-deque.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_back(2);
+	/// q.push_front(1);
+	/// *q.get_mut(1).unwrap()=3;
+	/// assert_eq!(q.get(1),Some(&3));
+	/// ```
+	pub fn get_mut(&mut self,index:usize)->Option<&mut T>
+	{
+		if index<self.length
+		{
+			let i=self.physical(index);
+			unsafe
+			{
+				Some(&mut *self.buf_mut_ptr().add(i))
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Returns a reference to the value at the front of the static-deque.
+	pub fn front(&self)->Option<&T>
+	{
+		self.get(0)
+	}
+
+	/// Returns a mutable reference to the value at the front of the static-deque.
+	pub fn front_mut(&mut self)->Option<&mut T>
+	{
+		self.get_mut(0)
+	}
+
+	/// Returns a reference to the value at the back of the static-deque.
+	pub fn back(&self)->Option<&T>
+	{
+		self.get(self.length.wrapping_sub(1))
+	}
+
+	/// Returns a mutable reference to the value at the back of the static-deque.
+	pub fn back_mut(&mut self)->Option<&mut T>
+	{
+		self.get_mut(self.length.wrapping_sub(1))
+	}
+
+	/// Returns a pair of slices which contain, in order, the contents of the static-deque. \
+	/// If `make_contiguous` was previously called, the second slice is empty.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_back(2);
+	/// q.push_back(3);
+	/// q.push_front(1);
+	/// assert_eq!(q.as_slices(),(&[1][..],&[2,3][..]));
+	/// ```
+	pub fn as_slices(&self)->(&[T],&[T])
+	{
+		let first_len=if self.head+self.length>N {N-self.head} else {self.length};
+		unsafe
+		{
+			let p=self.buf_ptr();
+			(slice::from_raw_parts(p.add(self.head),first_len),slice::from_raw_parts(p,self.length-first_len))
+		}
+	}
+
+	/// Returns a pair of mutable slices which contain, in order, the contents of the static-deque.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_back(2);
+	/// q.push_front(1);
+	/// let (a,b)=q.as_mut_slices();
+	/// a[0]=3;
+	/// b[0]=4;
+	/// assert_eq!(q.as_slices(),(&[3][..],&[4][..]));
+	/// ```
+	pub fn as_mut_slices(&mut self)->(&mut [T],&mut [T])
+	{
+		let first_len=if self.head+self.length>N {N-self.head} else {self.length};
+		let head=self.head;
+		let length=self.length;
+		unsafe
+		{
+			let p=self.buf_mut_ptr();
+			(slice::from_raw_parts_mut(p.add(head),first_len),slice::from_raw_parts_mut(p,length-first_len))
+		}
+	}
+
+	/// Rearranges the internal storage of the static-deque so it is one contiguous slice, which is then returned.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<4,u64>=StaticDeque::new();
+	/// q.push_back(2);
+	/// q.push_back(3);
+	/// q.push_front(1);
+	/// assert_eq!(q.make_contiguous(),&[1,2,3]);
+	/// assert_eq!(q.as_slices(),(&[1,2,3][..],&[][..]));
+	/// ```
+	pub fn make_contiguous(&mut self)->&mut [T]
+	{
+		if self.head+self.length>N
+		{
+			// Rotate the whole buffer, including the uninitialized slots, so that the front is at the beginning.
+			unsafe
+			{
+				let buff=slice::from_raw_parts_mut(self.buff.as_mut_ptr().cast::<MaybeUninit<T>>(),N);
+				buff.rotate_left(self.head);
+			}
+			self.head=0;
+		}
+		self.as_mut_slices().0
+	}
+
+	/// Rotates the static-deque `n` places to the left. \
+	/// The first `n` values are moved to the back of the static-deque.
+	/// 
+	/// # Panic
+	/// If `n>len`, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=(1..=5).collect();
+	/// q.rotate_left(2);
+	/// assert!(q.iter().eq([3,4,5,1,2].iter()));
+	/// ```
+	pub fn rotate_left(&mut self,n:usize)
+	{
+		assert!(n<=self.length,"Rotation {n} is out of length {}!",self.length);
+		if n<=self.length-n
+		{
+			for _ in 0..n
+			{
+				self.rotate_one_left();
+			}
+		}
+		else
+		{
+			for _ in 0..self.length-n
+			{
+				self.rotate_one_right();
+			}
+		}
+	}
+
+	/// Rotates the static-deque `n` places to the right. \
+	/// The last `n` values are moved to the front of the static-deque.
+	/// 
+	/// # Panic
+	/// If `n>len`, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=(1..=5).collect();
+	/// q.rotate_right(2);
+	/// assert!(q.iter().eq([4,5,1,2,3].iter()));
+	/// ```
+	pub fn rotate_right(&mut self,n:usize)
+	{
+		assert!(n<=self.length,"Rotation {n} is out of length {}!",self.length);
+		self.rotate_left(self.length-n);
+	}
+
+	// Moves the front value to the back.
+	fn rotate_one_left(&mut self)
+	{
+		if self.length<N
+		{
+			let dst=self.physical(self.length);
+			unsafe
+			{
+				let p=self.buf_mut_ptr();
+				ptr::copy_nonoverlapping(p.add(self.head),p.add(dst),1);
+			}
+		}
+		self.head=self.physical(1);
+	}
+
+	// Moves the back value to the front.
+	fn rotate_one_right(&mut self)
+	{
+		let src=self.physical(self.length-1);
+		self.head=if self.head==0 {N-1} else {self.head-1};
+		if self.length<N
+		{
+			unsafe
+			{
+				let p=self.buf_mut_ptr();
+				ptr::copy_nonoverlapping(p.add(src),p.add(self.head),1);
+			}
+		}
+	}
+
+	/// Returns a front-to-back iterator of the static-deque.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=StaticDeque::new();
+	/// q.push_back(2);
+	/// q.push_front(1);
+	/// let mut it=q.iter();
+	/// assert_eq!(it.next(),Some(&1));
+	/// assert_eq!(it.next(),Some(&2));
+	/// assert_eq!(it.next(),None);
+	/// ```
+	pub fn iter(&self)->Iter<'_,T>
+	{
+		let (a,b)=self.as_slices();
+		Iter
+		{
+			first:a.iter(),
+			second:b.iter()
+		}
+	}
+
+	/// Returns a front-to-back iterator that returns mutable references.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=(1..=3).collect();
+	/// q.iter_mut().for_each(|x| *x*=10);
+	/// assert!(q.iter().eq([10,20,30].iter()));
+	/// ```
+	pub fn iter_mut(&mut self)->IterMut<'_,T>
+	{
+		let (a,b)=self.as_mut_slices();
+		IterMut
+		{
+			first:a.iter_mut(),
+			second:b.iter_mut()
+		}
+	}
+
+	/// Removes the specified `range` from the static-deque, returning all removed values as an iterator. \
+	/// If the iterator is dropped before being fully consumed, the remaining removed values are dropped.
+	/// 
+	/// # Panic
+	/// If the starting point is greater than the end point, or if the end point is greater than the length, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=(1..=5).collect();
+	/// assert!(q.drain(1..3).eq([2,3]));
+	/// assert!(q.iter().eq([1,4,5].iter()));
+	/// ```
+	pub fn drain<R:RangeBounds<usize>>(&mut self,range:R)->Drain<'_,N,T>
+	{
+		let old_len=self.length;
+		let r=resolve_range(range,old_len);
+		// Shorten the static-deque first, so leaking the `Drain` would not expose moved values.
+		self.length=r.start;
+		Drain
+		{
+			deque:self,
+			index:r.start,
+			end:r.end,
+			tail_len:old_len-r.end
+		}
+	}
+
+	/// Shortens this static-deque to the specified `new_len`, dropping values at the back.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=(1..=5).collect();
+	/// q.truncate(2);
+	/// assert!(q.iter().eq([1,2].iter()));
+	/// ```
+	pub fn truncate(&mut self,new_len:usize)
+	{
+		while self.length>new_len
+		{
+			// Force drop every item.
+			drop(self.pop_back());
+		}
+	}
+
+	/// Removes all values from the static-deque.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::deque::StaticDeque;
+	/// let mut q:StaticDeque<8,u64>=(1..=5).collect();
+	/// q.clear();
+	/// assert!(q.is_empty());
+	/// ```
+	pub fn clear(&mut self)
+	{
+		self.truncate(0);
+		self.head=0;
+	}
+
+	/// This routine is a helper routine for the `deque_static!` macro. \
+	/// This routine does nothing at runtime, but it fails to compile if `M>N`, so the capacity is checked at compile time.
+	/// 
+	/// Do not use this routine on your own. This routine is only supposed to be called by `deque_static!` macro.
+	#[doc(hidden)] pub const fn _assert_capacity<const M:usize>(&self)
+	{
+		const{assert!(M<=N,"The length exceeds capacity!")};
+	}
+
+	/// This routine is a helper routine that constructs a static-deque for the `deque_static!` macro. \
+	/// This routine assigns the `value` specified at location `index` in the static-deque.
+	/// 
+	/// # Safety
+	/// Do not use this routine on your own. This routine is only supposed to be called by `deque_static!` macro, \
+	/// and the `index` is allowed to go beyond the `self.len()` limit. That's why this routine is unsafe.
+	/// 
+	/// # Panic
+	/// If `index>=N`, internal assertion would fail.
+	pub const unsafe fn force_assign(&mut self,index:usize,value:T)
+	{
+		assert!(index<N);
+		let i=self.physical(index);
+		unsafe
+		{
+			ptr::write(self.buf_mut_ptr().add(i),value);
+		}
+	}
+
+	/// This routine is a helper routine that constructs a static-deque for the `deque_static!` macro. \
+	/// This routine resizes the static-deque with specified length.
+	/// 
+	/// # Safety
+	/// If `length>self.length`, the static-deque may end with undefined contents. \
+	/// You should get rid of these undefined contents after you force resized this deque.
+	/// 
+	/// # Panic
+	/// If `length>N`, internal assertion would fail.
+	pub const unsafe fn force_resize(&mut self,length:usize)
+	{
+		assert!(length<=N,"The new length exceeds capacity!");
+		self.length=length;
+	}
+}
+
+impl<const N:usize,T> Drop for StaticDeque<N,T>
+{
+	fn drop(&mut self)
+	{
+		self.clear();
+	}
+}
+
+impl<const N:usize,T:Clone> Clone for StaticDeque<N,T>
+{
+	fn clone(&self) -> Self
+	{
+		let mut q=Self::new();
+		for item in self.iter()
+		{
+			q.push_back(item.clone());
+		}
+		q
+	}
+}
+
+impl<const N:usize,T:fmt::Debug> fmt::Debug for StaticDeque<N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<const N:usize,T> Index<usize> for StaticDeque<N,T>
+{
+	type Output = T;
+
+	fn index(&self, index: usize) -> &Self::Output
+	{
+		match self.get(index)
+		{
+			Some(v)=>v,
+			None=>panic!("Index {index} is out of length {}!",self.length)
+		}
+	}
+}
+
+impl<const N:usize,T> IndexMut<usize> for StaticDeque<N,T>
+{
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output
+	{
+		let length=self.length;
+		match self.get_mut(index)
+		{
+			Some(v)=>v,
+			None=>panic!("Index {index} is out of length {length}!")
+		}
+	}
+}
+
+impl<const N:usize,T> Extend<T> for StaticDeque<N,T>
+{
+	/// Appends all values yielded by `iter` to the back of static-deque.
+	/// 
+	/// # Panic
+	/// If the static-deque cannot hold all values, this method would panic.
+	fn extend<I:IntoIterator<Item=T>>(&mut self,iter:I)
+	{
+		for item in iter
+		{
+			self.push_back(item);
+		}
+	}
+}
+
+impl<const N:usize,T> FromIterator<T> for StaticDeque<N,T>
+{
+	/// Constructs a new static-deque from the values yielded by `iter`.
+	/// 
+	/// # Panic
+	/// If `iter` yields more than `N` values, this method would panic.
+	fn from_iter<I:IntoIterator<Item=T>>(iter:I)->Self
+	{
+		let mut q=Self::new();
+		q.extend(iter);
+		q
+	}
+}
+
+impl<const N:usize,T> IntoIterator for StaticDeque<N,T>
+{
+	type Item = T;
+	type IntoIter = IntoIter<N,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		IntoIter
+		{
+			deque:self
+		}
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a StaticDeque<N,T>
+{
+	type Item = &'a T;
+	type IntoIter = Iter<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a mut StaticDeque<N,T>
+{
+	type Item = &'a mut T;
+	type IntoIter = IterMut<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+/// The iterator of `StaticDeque`, created by `StaticDeque::iter` method.
+#[derive(Clone)]
+pub struct Iter<'a,T>
+{
+	first:slice::Iter<'a,T>,
+	second:slice::Iter<'a,T>
+}
+
+impl<'a,T> Iterator for Iter<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		match self.first.next()
+		{
+			Some(v)=>Some(v),
+			None=>self.second.next()
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		let l=self.first.len()+self.second.len();
+		(l,Some(l))
+	}
+}
+
+impl<'a,T> DoubleEndedIterator for Iter<'a,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		match self.second.next_back()
+		{
+			Some(v)=>Some(v),
+			None=>self.first.next_back()
+		}
+	}
+}
+
+impl<'a,T> ExactSizeIterator for Iter<'a,T> {}
+impl<'a,T> FusedIterator for Iter<'a,T> {}
+
+/// The mutable iterator of `StaticDeque`, created by `StaticDeque::iter_mut` method.
+pub struct IterMut<'a,T>
+{
+	first:slice::IterMut<'a,T>,
+	second:slice::IterMut<'a,T>
+}
+
+impl<'a,T> Iterator for IterMut<'a,T>
+{
+	type Item = &'a mut T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		match self.first.next()
+		{
+			Some(v)=>Some(v),
+			None=>self.second.next()
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		let l=self.first.len()+self.second.len();
+		(l,Some(l))
+	}
+}
+
+impl<'a,T> DoubleEndedIterator for IterMut<'a,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		match self.second.next_back()
+		{
+			Some(v)=>Some(v),
+			None=>self.first.next_back()
+		}
+	}
+}
+
+impl<'a,T> ExactSizeIterator for IterMut<'a,T> {}
+impl<'a,T> FusedIterator for IterMut<'a,T> {}
+
+/// The by-value iterator of `StaticDeque`. \
+/// Values that are not yielded are dropped when the iterator is dropped.
+pub struct IntoIter<const N:usize,T>
+{
+	deque:StaticDeque<N,T>
+}
+
+impl<const N:usize,T> Iterator for IntoIter<N,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.deque.pop_front()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.deque.len(),Some(self.deque.len()))
+	}
+}
+
+impl<const N:usize,T> DoubleEndedIterator for IntoIter<N,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.deque.pop_back()
+	}
+}
+
+impl<const N:usize,T> ExactSizeIterator for IntoIter<N,T> {}
+impl<const N:usize,T> FusedIterator for IntoIter<N,T> {}
+
+/// The draining iterator of `StaticDeque`, created by `StaticDeque::drain` method. \
+/// Values that are not yielded are dropped when the iterator is dropped.
+pub struct Drain<'a,const N:usize,T>
+{
+	deque:&'a mut StaticDeque<N,T>,
+	index:usize,
+	end:usize,
+	tail_len:usize
+}
+
+impl<'a,const N:usize,T> Iterator for Drain<'a,N,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.index<self.end
+		{
+			let i=self.deque.physical(self.index);
+			self.index+=1;
+			unsafe
+			{
+				Some(ptr::read(self.deque.buf_ptr().add(i)))
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		let l=self.end-self.index;
+		(l,Some(l))
+	}
+}
+
+impl<'a,const N:usize,T> DoubleEndedIterator for Drain<'a,N,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		if self.index<self.end
+		{
+			self.end-=1;
+			let i=self.deque.physical(self.end);
+			unsafe
+			{
+				Some(ptr::read(self.deque.buf_ptr().add(i)))
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+}
+
+impl<'a,const N:usize,T> ExactSizeIterator for Drain<'a,N,T> {}
+impl<'a,const N:usize,T> FusedIterator for Drain<'a,N,T> {}
+
+impl<'a,const N:usize,T> Drop for Drain<'a,N,T>
+{
+	fn drop(&mut self)
+	{
+		// Close the gap even if dropping the remaining values panics.
+		struct GapGuard<'b,'a,const N:usize,T>(&'b mut Drain<'a,N,T>);
+
+		impl<'b,'a,const N:usize,T> Drop for GapGuard<'b,'a,N,T>
+		{
+			fn drop(&mut self)
+			{
+				let d=&mut *self.0;
+				let q=&mut *d.deque;
+				let start=q.length;
+				let tail_start=d.end;
+				let gap=tail_start-start;
+				if gap>0
+				{
+					// Move whichever side of the gap is shorter.
+					if start<=d.tail_len
+					{
+						for i in (0..start).rev()
+						{
+							let (src,dst)=(q.physical(i),q.physical(i+gap));
+							unsafe
+							{
+								let p=q.buf_mut_ptr();
+								ptr::copy_nonoverlapping(p.add(src),p.add(dst),1);
+							}
+						}
+						q.head=q.physical(gap);
+					}
+					else
+					{
+						for i in 0..d.tail_len
+						{
+							let (src,dst)=(q.physical(tail_start+i),q.physical(start+i));
+							unsafe
+							{
+								let p=q.buf_mut_ptr();
+								ptr::copy_nonoverlapping(p.add(src),p.add(dst),1);
+							}
+						}
+					}
+				}
+				q.length=start+d.tail_len;
+			}
+		}
+
+		let g=GapGuard(self);
+		while g.0.index<g.0.end
+		{
+			let i=g.0.deque.physical(g.0.index);
+			g.0.index+=1;
+			// Force drop every remaining item.
+			unsafe
+			{
+				ptr::drop_in_place(g.0.deque.buf_mut_ptr().add(i));
+			}
+		}
+	}
+}
+
+/// The `deque_static!` macro helps building a static-deque easily,
+/// similar to the `vec_static!` macro.
+/// 
+/// The length of a list and a literal repeat count are checked against the capacity at compile time. \
+/// Otherwise, the repeat count of `deque_static![elem;len]` may be a runtime value, and exceeding the capacity panics.
+/// 
+/// # Example
+/// ```
+/// use static_collections::{deque_static,deque::StaticDeque};
+/// let a:StaticDeque<12,u64>=deque_static![4;7];
+/// assert_eq!(a.as_slices(),(&[4;7][..],&[][..]));
+/// let n=a.len()+1;
+/// let c:StaticDeque<12,u64>=deque_static![1;n];
+/// assert_eq!(c.len(),8);
+/// let d:StaticDeque<12,u64>=deque_static![2;12];
+/// assert!(d.is_full());
+/// let b:StaticDeque<16,u32>=deque_static![1,2,3,4,5,6,7,8];
+/// assert_eq!(b.as_slices(),(&[1,2,3,4,5,6,7,8][..],&[][..]));
+/// ```
+/// 
+/// Exceeding the capacity is rejected at compile time:
+/// ```compile_fail
+/// use static_collections::{deque_static,deque::StaticDeque};
+/// let q:StaticDeque<2,u32>=deque_static![1,2,3];
+/// ```
+/// ```compile_fail
+/// use static_collections::{deque_static,deque::StaticDeque};
+/// let q:StaticDeque<2,u32>=deque_static![0;3];
+/// ```
+#[macro_export] macro_rules! deque_static
+{
+	()=>
+	(
+		$crate::deque::StaticDeque::new()
+	);
+	($elem:expr;$len:literal)=>
+	(
+		{
+			let mut q=$crate::deque::StaticDeque::new();
+			q._assert_capacity::<{$len}>();
+			let len:usize=$len;
+			let mut i:usize=0;
+			while i<len
+			{
+				let e=$elem;
+				unsafe
+				{
+					q.force_assign(i,e);
+				}
+				i+=1;
+			}
+			unsafe
+			{
+				q.force_resize(len);
+			}
+			q
+		}
+	);
+	($elem:expr;$len:expr)=>
+	(
+		{
+			let mut q=$crate::deque::StaticDeque::new();
+			let len:usize=$len;
+			let mut i:usize=0;
+			while i<len
+			{
+				let e=$elem;
+				unsafe
+				{
+					q.force_assign(i,e);
+				}
+				i+=1;
+			}
+			unsafe
+			{
+				q.force_resize(len);
+			}
+			q
+		}
+	);
+	($($x:expr),+$(,)?)=>
+	(
+		{
+			let mut q=$crate::deque::StaticDeque::new();
+			q._assert_capacity::<{[$(stringify!($x)),+].len()}>();
+			let mut index:usize=0;
+			$(
+				{
+					let e=$x;
+					unsafe
+					{
+						q.force_assign(index,e);
+					}
+					index+=1;
+				}
+			)*
+			unsafe
+			{
+				q.force_resize(index);
+			}
+			q
+		}
+	);
+}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use core::sync::atomic::{AtomicUsize,Ordering};
+	use std::vec::Vec;
+	use crate::deque::StaticDeque;
+
+	#[test] fn wrap_around()
+	{
+		let mut q:StaticDeque<4,u64>=StaticDeque::new();
+		for i in 0..10
+		{
+			q.push_back(i);
+			q.push_back(i+100);
+			assert_eq!(q.pop_front(),Some(i));
+			assert_eq!(q.pop_front(),Some(i+100));
+		}
+		q.push_back(1);
+		q.push_back(2);
+		q.push_front(0);
+		q.push_back(3);
+		assert!(q.is_full());
+		q.rotate_left(3);
+		assert_eq!(q.iter().copied().collect::<Vec<u64>>(),[3,0,1,2]);
+		q.pop_front();
+		q.rotate_right(1);
+		assert_eq!(q.iter().copied().collect::<Vec<u64>>(),[2,0,1]);
+		assert_eq!(q.make_contiguous(),&[2,0,1]);
+	}
+
+	#[test] fn drain()
+	{
+		for head in 0..8
+		{
+			for start in 0..=6
+			{
+				for end in start..=6
+				{
+					let mut q:StaticDeque<8,u64>=StaticDeque::new();
+					for _ in 0..head
+					{
+						q.push_back(0);
+						q.pop_front();
+					}
+					q.extend(0..6);
+					let d:Vec<u64>=q.drain(start..end).collect();
+					assert_eq!(d,(start as u64..end as u64).collect::<Vec<u64>>());
+					let rest:Vec<u64>=q.iter().copied().collect();
+					let expected:Vec<u64>=(0..start as u64).chain(end as u64..6).collect();
+					assert_eq!(rest,expected);
+				}
+			}
+		}
+	}
+
+	#[test] fn drop()
+	{
+		struct DropCounter<'a>
+		{
+			counter:&'a AtomicUsize
+		}
+
+		impl<'a> Drop for DropCounter<'a>
+		{
+			fn drop(&mut self)
+			{
+				self.counter.fetch_add(1,Ordering::SeqCst);
+			}
+		}
+
+		let drop_count:AtomicUsize=AtomicUsize::new(0);
+		{
+			let mut q:StaticDeque<4,DropCounter>=StaticDeque::new();
+			for _ in 0..3
+			{
+				q.push_front(DropCounter{counter:&drop_count});
+			}
+			q.pop_back();
+			assert_eq!(drop_count.load(Ordering::SeqCst),1);
+			q.push_back(DropCounter{counter:&drop_count});
+			q.push_back(DropCounter{counter:&drop_count});
+			let mut d=q.drain(1..3);
+			d.next();
+			core::mem::drop(d);
+			assert_eq!(drop_count.load(Ordering::SeqCst),3);
+			assert_eq!(q.len(),2);
+			let mut it=q.into_iter();
+			it.next();
+			assert_eq!(drop_count.load(Ordering::SeqCst),4);
+		}
+		assert_eq!(drop_count.load(Ordering::SeqCst),5);
+	}
+}
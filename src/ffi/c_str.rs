@@ -108,6 +108,45 @@ impl<const N:usize> StaticCString<N>
 		s
 	}
 
+	/// Creates a new `StaticCString` from a C-string. \
+	/// Unlike `From<&CStr>`, this method can be evaluated in constant contexts, so it can initialize a `static` item.
+	/// 
+	/// # Panic
+	/// If the static-string cannot hold the C-string with its null-terminator, this method would panic. \
+	/// In a constant context, the panic becomes a compile error.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::ffi::c_str::StaticCString;
+	/// static NAME:StaticCString<16>=StaticCString::from_c_str_const(c"Hello, World!");
+	/// assert_eq!(NAME.as_c_str(),c"Hello, World!");
+	/// ```
+	/// 
+	/// ```compile_fail
+	/// use static_collections::ffi::c_str::StaticCString;
+	/// static NAME:StaticCString<13>=StaticCString::from_c_str_const(c"Hello, World!");
+	/// ```
+	pub const fn from_c_str_const(value:&CStr)->Self
+	{
+		let b=value.to_bytes_with_nul();
+		if b.len()>N
+		{
+			panic!("String is too large!");
+		}
+		let mut s=Self{buffer:MaybeUninit::uninit()};
+		let p:*mut i8=s.buffer.as_mut_ptr().cast();
+		let mut i=0;
+		while i<b.len()
+		{
+			unsafe
+			{
+				p.add(i).write(b[i] as i8);
+			}
+			i+=1;
+		}
+		s
+	}
+
 	/// Returns the length of the static-string by using `strnlen`.
 	/// 
 	/// # Example
@@ -359,3 +398,115 @@ impl<const N:usize> Default for StaticCString<N>
 
 unsafe impl<const N:usize> Send for StaticCString<N> {}
 unsafe impl<const N:usize> Sync for StaticCString<N> {}
+
+// A static-string is serialized as bytes without the null-terminator, just like `CString` does.
+#[cfg(feature = "serde")]
+impl<const N:usize> serde::Serialize for StaticCString<N>
+{
+	fn serialize<S:serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_bytes(self.as_bytes())
+	}
+}
+
+// Deserializing a string that cannot fit in `N-1` bytes fails with an `invalid_length` error.
+// Null-characters inside the string are rejected with an `invalid_value` error.
+#[cfg(feature = "serde")]
+impl<'de,const N:usize> serde::Deserialize<'de> for StaticCString<N>
+{
+	fn deserialize<D:serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+	{
+		deserializer.deserialize_bytes(StaticCStringVisitor)
+	}
+}
+
+#[cfg(feature = "serde")]
+struct StaticCStringVisitor<const N:usize>;
+
+#[cfg(feature = "serde")]
+impl<'de,const N:usize> serde::de::Visitor<'de> for StaticCStringVisitor<N>
+{
+	type Value = StaticCString<N>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(formatter,"a string without null-characters of at most {} bytes",N-1)
+	}
+
+	fn visit_bytes<E:serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E>
+	{
+		if v.contains(&0)
+		{
+			Err(E::invalid_value(serde::de::Unexpected::Bytes(v),&self))
+		}
+		else if v.len()>=N
+		{
+			Err(E::invalid_length(v.len(),&self))
+		}
+		else
+		{
+			let mut s=StaticCString::new();
+			unsafe
+			{
+				let p=s.as_mut_ptr();
+				p.copy_from_nonoverlapping(v.as_ptr().cast(),v.len());
+				p.add(v.len()).write(0);
+			}
+			Ok(s)
+		}
+	}
+
+	fn visit_str<E:serde::de::Error>(self, v: &str) -> Result<Self::Value, E>
+	{
+		self.visit_bytes(v.as_bytes())
+	}
+
+	fn visit_seq<A:serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	{
+		let mut s=StaticCString::new();
+		let p=s.as_mut_ptr();
+		let mut length=0;
+		while let Some(c)=seq.next_element::<u8>()?
+		{
+			if c==0
+			{
+				return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(0),&self));
+			}
+			if length+1>=N
+			{
+				// The exact length is unknown, but there are at least `N` bytes.
+				return Err(serde::de::Error::invalid_length(N,&self));
+			}
+			unsafe
+			{
+				p.add(length).write(c as i8);
+			}
+			length+=1;
+		}
+		unsafe
+		{
+			p.add(length).write(0);
+		}
+		Ok(s)
+	}
+}
+
+#[cfg(all(test,feature = "serde"))] mod test
+{
+	use serde::{Deserialize, de::value::{BytesDeserializer, Error, StrDeserializer}};
+	use serde_test::{Token, assert_de_tokens_error, assert_ser_tokens};
+	use crate::ffi::c_str::StaticCString;
+
+	#[test] fn serde()
+	{
+		let s:StaticCString<8>=StaticCString::from(c"Hello");
+		assert_ser_tokens(&s,&[Token::Bytes(b"Hello")]);
+		let d=StaticCString::<8>::deserialize(BytesDeserializer::<Error>::new(b"Hello")).unwrap();
+		assert_eq!(d.as_c_str(),c"Hello");
+		let d=StaticCString::<8>::deserialize(StrDeserializer::<Error>::new("Hello")).unwrap();
+		assert_eq!(d.as_c_str(),c"Hello");
+		assert_de_tokens_error::<StaticCString<5>>(&[Token::Bytes(b"Hello")],"invalid length 5, expected a string without null-characters of at most 4 bytes");
+		assert_de_tokens_error::<StaticCString<8>>(&[Token::Bytes(b"He\0lo")],"invalid value: byte array, expected a string without null-characters of at most 7 bytes");
+		assert_de_tokens_error::<StaticCString<5>>(&[Token::Seq{len:Some(5)},Token::U8(b'H'),Token::U8(b'e'),Token::U8(b'l'),Token::U8(b'l'),Token::U8(b'o')],"invalid length 5, expected a string without null-characters of at most 4 bytes");
+	}
+}
@@ -1,8 +1,41 @@
 // The static-wstring module.
 
-use core::{fmt, mem::MaybeUninit, ops::{Index, IndexMut}, slice::SliceIndex};
+use core::{fmt, ops::{Index, IndexMut}, slice::SliceIndex};
 
-use crate::vec::StaticVec;
+use crate::vec::{CapacityError, StaticVec};
+
+// Counts the UTF-16 characters required to encode the string-slice.
+// Each character is counted by its leading byte, and 4-byte characters require a surrogate pair.
+const fn utf16_len(s:&str)->usize
+{
+	let b=s.as_bytes();
+	let mut i=0;
+	let mut n=0;
+	while i<b.len()
+	{
+		if b[i]&0xC0!=0x80
+		{
+			n+=if b[i]>=0xF0 {2} else {1};
+		}
+		i+=1;
+	}
+	n
+}
+
+// Decodes the character starting at byte `i` of the string-slice, and returns it with its length in bytes.
+const fn decode_utf8(s:&str,i:usize)->(char,usize)
+{
+	let b=s.as_bytes();
+	let (cp,len)=match b[i]
+	{
+		0x00..=0x7F=>(b[i] as u32,1),
+		0xC0..=0xDF=>((b[i] as u32&0x1F)<<6|(b[i+1] as u32&0x3F),2),
+		0xE0..=0xEF=>((b[i] as u32&0x0F)<<12|(b[i+1] as u32&0x3F)<<6|(b[i+2] as u32&0x3F),3),
+		_=>((b[i] as u32&0x07)<<18|(b[i+1] as u32&0x3F)<<12|(b[i+2] as u32&0x3F)<<6|(b[i+3] as u32&0x3F),4)
+	};
+	// The string-slice is valid UTF-8, so the code point is always a valid character.
+	(unsafe {char::from_u32_unchecked(cp)},len)
+}
 
 /// The `StaticWString` is a fixed-capacity UTF-16 string object.
 #[derive(Default, Debug, Clone)]
@@ -25,6 +58,33 @@ impl<const N:usize> StaticWString<N>
 		}
 	}
 
+	/// Creates a new `StaticWString` from a UTF-8 encoded string-slice. \
+	/// The string-slice is encoded into UTF-16 by this method, which can be evaluated in constant contexts. \
+	/// Therefore, a `static` item can be initialized from a string literal without any runtime encoding.
+	/// 
+	/// # Panic
+	/// If the string cannot hold the whole string-slice, this method would panic. \
+	/// In a constant context, the panic becomes a compile error.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::ffi::wstring::StaticWString;
+	/// use utf16_lit::utf16;
+	/// static TITLE:StaticWString<16>=StaticWString::from_str_const("Hello, 世界😀");
+	/// assert_eq!(TITLE.as_slice(),utf16!("Hello, 世界😀"));
+	/// ```
+	/// 
+	/// ```compile_fail
+	/// use static_collections::ffi::wstring::StaticWString;
+	/// static TITLE:StaticWString<4>=StaticWString::from_str_const("Hello, World!");
+	/// ```
+	pub const fn from_str_const(s:&str)->Self
+	{
+		let mut r=Self::new();
+		r.push_str(s);
+		r
+	}
+
 
 	/// Obtains the length of this string, in number of UTF-16 characters. \
 	/// If a character cannot fit in a single UTF-16 range (e.g.: emoji), it will be counted as 2 characters.
@@ -120,6 +180,10 @@ impl<const N:usize> StaticWString<N>
 
 	/// Inserts a character to the end of the string.
 	/// 
+	/// # Panic
+	/// If the string cannot hold the character, this method would panic. \
+	/// Use `try_push_char` method if you would like to handle the overflow.
+	/// 
 	/// # Example
 	/// ```
 	/// use static_collections::ffi::wstring::StaticWString;
@@ -128,25 +192,51 @@ impl<const N:usize> StaticWString<N>
 	/// assert_eq!(s.len(),1);
 	/// assert_eq!(s.as_slice(),[b'a' as u16]);
 	/// ```
-	pub fn push_char(&mut self,ch:char)
+	pub const fn push_char(&mut self,ch:char)
 	{
-		let rsvd_size=ch.len_utf16();
-		if self.capacity()-self.len()>rsvd_size
+		if self.try_push_char(ch).is_err()
 		{
-			unsafe
+			panic!("StaticWString buffer overflow!");
+		}
+	}
+
+	/// Inserts a character to the end of the string. \
+	/// Returns `Err(CapacityError<char>)` with the character if the string cannot hold it.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::ffi::wstring::StaticWString;
+	/// let mut s:StaticWString<2>=StaticWString::new();
+	/// assert!(s.try_push_char('a').is_ok());
+	/// assert_eq!(s.try_push_char('😀').unwrap_err().into_inner(),'😀');
+	/// assert_eq!(s.as_slice(),[b'a' as u16]);
+	/// ```
+	pub const fn try_push_char(&mut self,ch:char)->Result<(),CapacityError<char>>
+	{
+		let mut x:[u16;2]=[0;2];
+		let u=ch.encode_utf16(&mut x);
+		if u.len()>N-self.len()
+		{
+			Err(CapacityError::new(ch))
+		}
+		else
+		{
+			let mut i=0;
+			while i<u.len()
 			{
-				let mut x:MaybeUninit<[u16;2]>=MaybeUninit::uninit();
-				let u=ch.encode_utf16(x.assume_init_mut());
-				for c in u
-				{
-					self.internal.push(*c);
-				}
+				self.internal.push(u[i]);
+				i+=1;
 			}
+			Ok(())
 		}
 	}
 
 	/// Inserts a UTF-8 encoded string-slice to the end of the string.
 	/// 
+	/// # Panic
+	/// If the string cannot hold the whole string-slice, this method would panic. \
+	/// Use `try_push_str` method if you would like to handle the overflow.
+	/// 
 	/// # Example
 	/// ```
 	/// use static_collections::ffi::wstring::StaticWString;
@@ -155,16 +245,61 @@ impl<const N:usize> StaticWString<N>
 	/// s.push_str("Hello, World!");
 	/// assert_eq!(s.as_slice(),utf16!("Hello, World!"));
 	/// ```
-	pub fn push_str(&mut self,s:&str)
+	pub const fn push_str(&mut self,s:&str)
 	{
-		for c in s.encode_utf16()
+		if self.try_push_str(s).is_err()
 		{
-			self.internal.push(c)
+			panic!("StaticWString buffer overflow!");
+		}
+	}
+
+	/// Inserts a UTF-8 encoded string-slice to the end of the string. \
+	/// Returns `Err(CapacityError<&str>)` with the string-slice if the string cannot hold it.
+	/// In this case, the string is not modified.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::ffi::wstring::StaticWString;
+	/// use utf16_lit::utf16;
+	/// let mut s:StaticWString<8>=StaticWString::new();
+	/// assert!(s.try_push_str("Hello").is_ok());
+	/// assert_eq!(s.try_push_str(", World!").unwrap_err().into_inner(),", World!");
+	/// assert_eq!(s.as_slice(),utf16!("Hello"));
+	/// ```
+	pub const fn try_push_str<'a>(&mut self,s:&'a str)->Result<(),CapacityError<&'a str>>
+	{
+		// Walk through the string-slice twice in order to avoid dynamic allocations.
+		// The `encode_utf16` iterator is not used, because it cannot be evaluated in constant contexts.
+		if utf16_len(s)>N-self.len()
+		{
+			Err(CapacityError::new(s))
+		}
+		else
+		{
+			let mut i=0;
+			while i<s.len()
+			{
+				let (ch,ch_len)=decode_utf8(s,i);
+				let mut x:[u16;2]=[0;2];
+				let u=ch.encode_utf16(&mut x);
+				let mut j=0;
+				while j<u.len()
+				{
+					self.internal.push(u[j]);
+					j+=1;
+				}
+				i+=ch_len;
+			}
+			Ok(())
 		}
 	}
 
 	/// Inserts a character to the position specifed by `index`.
 	/// 
+	/// # Panic
+	/// If the string cannot hold the character, or if `index>len`, this method would panic. \
+	/// Use `try_insert_char` method if you would like to handle the overflow.
+	/// 
 	/// # Example
 	/// ```
 	/// use static_collections::ffi::wstring::StaticWString;
@@ -175,25 +310,59 @@ impl<const N:usize> StaticWString<N>
 	/// ```
 	pub fn insert_char(&mut self,index:usize,ch:char)
 	{
-		let mut x:MaybeUninit<[u16;2]>=MaybeUninit::uninit();
-		let rsvd_size=ch.len_utf16();
-		if self.capacity()-self.len()>rsvd_size
+		if self.try_insert_char(index,ch).is_err()
 		{
-			let copy_range=index..self.len();
-			let u=unsafe
-			{
-				self.internal.force_resize(self.len()+rsvd_size);
-				ch.encode_utf16(x.assume_init_mut())
-			};
-			self.internal.copy_within(copy_range,index+rsvd_size);
-			for (i,c) in u.iter().enumerate()
+			panic!("StaticWString buffer overflow!");
+		}
+	}
+
+	/// Inserts a character to the position specifed by `index`. \
+	/// Returns `Err(CapacityError<char>)` with the character if the string cannot hold it.
+	/// 
+	/// # Panic
+	/// If `index>len`, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::ffi::wstring::StaticWString;
+	/// use utf16_lit::utf16;
+	/// let mut s:StaticWString<3>=StaticWString::from("ac");
+	/// assert!(s.try_insert_char(1,'b').is_ok());
+	/// assert_eq!(s.try_insert_char(1,'x').unwrap_err().into_inner(),'x');
+	/// assert_eq!(s.as_slice(),utf16!("abc"));
+	/// ```
+	pub fn try_insert_char(&mut self,index:usize,ch:char)->Result<(),CapacityError<char>>
+	{
+		let old_len=self.len();
+		if index>old_len
+		{
+			panic!("Insertion index {index} is out of length {old_len}!");
+		}
+		let mut x:[u16;2]=[0;2];
+		let u=ch.encode_utf16(&mut x);
+		let rsvd_size=u.len();
+		if rsvd_size>N-old_len
+		{
+			Err(CapacityError::new(ch))
+		}
+		else
+		{
+			unsafe
 			{
-				self[index+i]= *c;
+				self.internal.force_resize(old_len+rsvd_size);
 			}
+			self.internal.copy_within(index..old_len,index+rsvd_size);
+			self[index..index+rsvd_size].copy_from_slice(u);
+			Ok(())
 		}
 	}
+
 	/// Inserts a UTF-8-encoded string-slice to the position specified by `index`.
 	/// 
+	/// # Panic
+	/// If the string cannot hold the whole string-slice, or if `index>len`, this method would panic. \
+	/// Use `try_insert_str` method if you would like to handle the overflow.
+	/// 
 	/// # Example
 	/// ```
 	/// use static_collections::ffi::wstring::StaticWString;
@@ -204,19 +373,54 @@ impl<const N:usize> StaticWString<N>
 	/// ```
 	pub fn insert_str(&mut self,index:usize,s:&str)
 	{
+		if self.try_insert_str(index,s).is_err()
+		{
+			panic!("StaticWString buffer overflow!");
+		}
+	}
+
+	/// Inserts a UTF-8-encoded string-slice to the position specified by `index`. \
+	/// Returns `Err(CapacityError<&str>)` with the string-slice if the string cannot hold it.
+	/// In this case, the string is not modified.
+	/// 
+	/// # Panic
+	/// If `index>len`, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::ffi::wstring::StaticWString;
+	/// use utf16_lit::utf16;
+	/// let mut s:StaticWString<9>=StaticWString::from("123789");
+	/// assert!(s.try_insert_str(3,"456").is_ok());
+	/// assert_eq!(s.try_insert_str(3,"0").unwrap_err().into_inner(),"0");
+	/// assert_eq!(s.as_slice(),utf16!("123456789"));
+	/// ```
+	pub fn try_insert_str<'a>(&mut self,index:usize,s:&'a str)->Result<(),CapacityError<&'a str>>
+	{
+		let old_len=self.len();
+		if index>old_len
+		{
+			panic!("Insertion index {index} is out of length {old_len}!");
+		}
 		// Use `encode_utf16` iterator twice in order to avoid dynamic allocations.
 		// To avoid repeated memmoves, we need to count the number of UTF-16 characters.
 		let insert_len:usize=s.encode_utf16().count();
-		let copy_range=index..self.len();
-		// May-Panic: The `force_resize` will panic if overflow.
-		unsafe
+		if insert_len>N-old_len
 		{
-			self.internal.force_resize(self.len()+insert_len);
+			Err(CapacityError::new(s))
 		}
-		self.internal.copy_within(copy_range,index+insert_len);
-		for (i,c) in s.encode_utf16().enumerate()
+		else
 		{
-			self[index+i]=c;
+			unsafe
+			{
+				self.internal.force_resize(old_len+insert_len);
+			}
+			self.internal.copy_within(index..old_len,index+insert_len);
+			for (i,c) in s.encode_utf16().enumerate()
+			{
+				self[index+i]=c;
+			}
+			Ok(())
 		}
 	}
 }
@@ -243,9 +447,7 @@ impl<const N:usize> From<&str> for StaticWString<N>
 {
 	fn from(value: &str) -> Self
 	{
-		let mut s=Self::new();
-		s.push_str(value);
-		s
+		Self::from_str_const(value)
 	}
 }
 
@@ -302,14 +504,20 @@ impl<const N:usize> fmt::Write for StaticWString<N>
 {
 	fn write_char(&mut self, c: char) -> fmt::Result
 	{
-		self.push_char(c);
-		Ok(())
+		match self.try_push_char(c)
+		{
+			Ok(())=>Ok(()),
+			Err(_)=>Err(fmt::Error)
+		}
 	}
 
 	fn write_str(&mut self, s: &str) -> fmt::Result
 	{
-		self.push_str(s);
-		Ok(())
+		match self.try_push_str(s)
+		{
+			Ok(())=>Ok(()),
+			Err(_)=>Err(fmt::Error)
+		}
 	}
 }
 
@@ -370,12 +578,65 @@ impl<'a,const N:usize> IntoIterator for StaticWIter<'a,N>
 	}
 }
 
+// A static-wstring is serialized as a UTF-8 string, so it is readable in text formats.
+// Unpaired surrogates cannot be represented in UTF-8, so serializing them fails instead of losing data.
+#[cfg(feature = "serde")]
+impl<const N:usize> serde::Serialize for StaticWString<N>
+{
+	fn serialize<S:serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		if char::decode_utf16(self.iter()).any(|c| c.is_err())
+		{
+			Err(serde::ser::Error::custom("The string contains unpaired surrogates!"))
+		}
+		else
+		{
+			serializer.collect_str(self)
+		}
+	}
+}
+
+// Deserializing a string longer than `N` UTF-16 characters fails with an `invalid_length` error.
+#[cfg(feature = "serde")]
+impl<'de,const N:usize> serde::Deserialize<'de> for StaticWString<N>
+{
+	fn deserialize<D:serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+	{
+		deserializer.deserialize_str(StaticWStringVisitor)
+	}
+}
+
+#[cfg(feature = "serde")]
+struct StaticWStringVisitor<const N:usize>;
+
+#[cfg(feature = "serde")]
+impl<'de,const N:usize> serde::de::Visitor<'de> for StaticWStringVisitor<N>
+{
+	type Value = StaticWString<N>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(formatter,"a string of at most {N} UTF-16 characters")
+	}
+
+	fn visit_str<E:serde::de::Error>(self, v: &str) -> Result<Self::Value, E>
+	{
+		let mut s=StaticWString::new();
+		match s.try_push_str(v)
+		{
+			Ok(())=>Ok(s),
+			Err(_)=>Err(E::invalid_length(utf16_len(v),&self))
+		}
+	}
+}
+
 #[cfg(test)]
 mod test
 {
 	extern crate std;
 	use std::format;
 	use super::StaticWString;
+	use crate::xorshift;
 
 	#[test] fn correct_fmt()
 	{
@@ -383,4 +644,46 @@ mod test
 		let ss=format!("This is {s}!");
 		assert_eq!(ss,"This is abcd魑魅魍魉1234😀🤣😅👍!");
 	}
+
+	#[test] fn write_overflow()
+	{
+		use core::fmt::Write;
+		let mut s:StaticWString<8>=StaticWString::new();
+		assert!(s.write_str("Hello").is_ok());
+		assert!(s.write_str(", World!").is_err());
+		assert_eq!(s,utf16_lit::utf16!("Hello"));
+	}
+
+	#[test] fn const_encoding()
+	{
+		const S:StaticWString<32>=StaticWString::from_str_const("a\u{7f}\u{80}\u{7ff}\u{800}\u{ffff}\u{10000}\u{10ffff}");
+		assert!(S.as_slice().iter().copied().eq("a\u{7f}\u{80}\u{7ff}\u{800}\u{ffff}\u{10000}\u{10ffff}".encode_utf16()));
+		let mut state:u64=0x2545_F491_4F6C_DD1D;
+		for _ in 0..10000
+		{
+			let r=xorshift(&mut state);
+			let Some(ch)=char::from_u32((r%0x11_0000) as u32) else {continue};
+			let mut b=[0;4];
+			let t=ch.encode_utf8(&mut b);
+			let w:StaticWString<2>=StaticWString::from_str_const(t);
+			assert!(w.as_slice().iter().copied().eq(t.encode_utf16()));
+		}
+	}
+
+	#[cfg(feature = "serde")]
+	#[test] fn serde()
+	{
+		use serde::Deserialize;
+		use serde::de::value::{Error, StrDeserializer};
+		use serde_test::{Token, assert_de_tokens_error, assert_ser_tokens, assert_ser_tokens_error};
+		let s:StaticWString<8>=StaticWString::from("Hi😀");
+		assert_ser_tokens(&s,&[Token::Str("Hi😀")]);
+		let d=StaticWString::<8>::deserialize(StrDeserializer::<Error>::new("Hi😀")).unwrap();
+		assert_eq!(d.as_slice(),s.as_slice());
+		assert_de_tokens_error::<StaticWString<3>>(&[Token::Str("Hi😀")],"invalid length 4, expected a string of at most 3 UTF-16 characters");
+		let mut s:StaticWString<8>=StaticWString::new();
+		s.push_str("Hi");
+		s[1]=0xD800;
+		assert_ser_tokens_error(&s,&[],"The string contains unpaired surrogates!");
+	}
 }
\ No newline at end of file
@@ -0,0 +1,1126 @@
+// The static-hash-map module
+
+use core::{borrow::Borrow, fmt, hash::{BuildHasher, Hash, Hasher}, iter::FusedIterator, mem, ops::Index, slice};
+
+use crate::vec::CapacityError;
+
+/// The `FxHasher` is a fast and deterministic non-cryptographic hasher. \
+/// It is the hashing algorithm used by the Rust compiler.
+/// 
+/// The `FxHasher` is not resistant to hash-flooding attacks. Use another `BuildHasher` if keys may be chosen by an attacker.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct FxHasher
+{
+	hash:u64
+}
+
+impl FxHasher
+{
+	const SEED:u64=0x51_7c_c1_b7_27_22_0a_95;
+
+	#[inline(always)] fn add_to_hash(&mut self,word:u64)
+	{
+		self.hash=(self.hash.rotate_left(5)^word).wrapping_mul(Self::SEED);
+	}
+}
+
+impl Hasher for FxHasher
+{
+	fn write(&mut self, bytes: &[u8])
+	{
+		let mut chunks=bytes.chunks_exact(8);
+		for c in chunks.by_ref()
+		{
+			self.add_to_hash(u64::from_le_bytes([c[0],c[1],c[2],c[3],c[4],c[5],c[6],c[7]]));
+		}
+		for b in chunks.remainder()
+		{
+			self.add_to_hash(*b as u64);
+		}
+	}
+
+	fn write_u8(&mut self, i: u8)
+	{
+		self.add_to_hash(i as u64);
+	}
+
+	fn write_u16(&mut self, i: u16)
+	{
+		self.add_to_hash(i as u64);
+	}
+
+	fn write_u32(&mut self, i: u32)
+	{
+		self.add_to_hash(i as u64);
+	}
+
+	fn write_u64(&mut self, i: u64)
+	{
+		self.add_to_hash(i);
+	}
+
+	fn write_usize(&mut self, i: usize)
+	{
+		self.add_to_hash(i as u64);
+	}
+
+	fn finish(&self) -> u64
+	{
+		self.hash
+	}
+}
+
+/// The `FxBuildHasher` creates `FxHasher` instances. It is the default hasher builder of `StaticHashMap`.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct FxBuildHasher;
+
+impl BuildHasher for FxBuildHasher
+{
+	type Hasher = FxHasher;
+
+	fn build_hasher(&self) -> Self::Hasher
+	{
+		FxHasher::default()
+	}
+}
+
+#[derive(Clone)]
+struct Bucket<K,V>
+{
+	hash:usize,
+	key:K,
+	value:V
+}
+
+// The result of probing a key in the slots.
+enum Probe
+{
+	// The key is found in this slot.
+	Found(usize),
+	// The key is not found. It can be inserted to this empty slot.
+	Vacant(usize),
+	// The key is not found, and there is no empty slot.
+	Full
+}
+
+/// The `StaticHashMap` type is a fixed-capacity hash map which can hold `N` key-value pairs at most. \
+/// It uses open addressing with linear probing. Removal shifts the following entries backward, so there are no tombstones.
+/// 
+/// The hasher `S` defaults to `FxBuildHasher`, which is deterministic and does not require a random source.
+/// 
+/// # Example
+/// ```
+/// use static_collections::hash_map::StaticHashMap;
+/// let mut m:StaticHashMap<16,&str,u32>=StaticHashMap::new();
+/// assert_eq!(m.insert("apple",3),Ok(None));
+/// assert_eq!(m.insert("apple",5),Ok(Some(3)));
+/// assert_eq!(m.get("apple"),Some(&5));
+/// assert_eq!(m.remove("apple"),Some(5));
+/// assert!(m.is_empty());
+/// ```
+pub struct StaticHashMap<const N:usize,K,V,S=FxBuildHasher>
+{
+	length:usize,
+	slots:[Option<Bucket<K,V>>;N],
+	hasher:S
+}
+
+impl<const N:usize,K,V> StaticHashMap<N,K,V,FxBuildHasher>
+{
+	/// Constructs a new, empty `StaticHashMap` with the default `FxBuildHasher`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let m:StaticHashMap<16,u32,u32>=StaticHashMap::new();
+	/// assert_eq!(m.len(),0);
+	/// ```
+	pub const fn new()->Self
+	{
+		Self::with_hasher(FxBuildHasher)
+	}
+}
+
+impl<const N:usize,K,V,S> StaticHashMap<N,K,V,S>
+{
+	/// Constructs a new, empty `StaticHashMap` which will use the given hasher builder to hash keys.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::{StaticHashMap,FxBuildHasher};
+	/// let m:StaticHashMap<16,u32,u32>=StaticHashMap::with_hasher(FxBuildHasher);
+	/// assert_eq!(m.capacity(),16);
+	/// ```
+	pub const fn with_hasher(hasher:S)->Self
+	{
+		Self
+		{
+			length:0,
+			slots:[const {None};N],
+			hasher
+		}
+	}
+
+	/// Returns the number of key-value pairs in the static-hash-map.
+	pub const fn len(&self)->usize
+	{
+		self.length
+	}
+
+	/// Checks if the static-hash-map is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.length==0
+	}
+
+	/// Checks if the static-hash-map is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.length==N
+	}
+
+	/// Returns the number of key-value pairs the static-hash-map can hold.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Returns a reference to the hasher builder of the static-hash-map.
+	pub const fn hasher(&self)->&S
+	{
+		&self.hasher
+	}
+
+	/// Returns an iterator of all key-value pairs in arbitrary order.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let m:StaticHashMap<8,u32,u32>=[(1,10),(2,20)].into_iter().collect();
+	/// assert_eq!(m.iter().map(|(k,v)| k+v).sum::<u32>(),33);
+	/// ```
+	pub fn iter(&self)->Iter<'_,K,V>
+	{
+		Iter
+		{
+			slots:self.slots.iter(),
+			remaining:self.length
+		}
+	}
+
+	/// Returns an iterator of all key-value pairs in arbitrary order, with mutable references to the values.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let mut m:StaticHashMap<8,u32,u32>=[(1,10),(2,20)].into_iter().collect();
+	/// m.iter_mut().for_each(|(_,v)| *v+=1);
+	/// assert_eq!(m[&2],21);
+	/// ```
+	pub fn iter_mut(&mut self)->IterMut<'_,K,V>
+	{
+		IterMut
+		{
+			slots:self.slots.iter_mut(),
+			remaining:self.length
+		}
+	}
+
+	/// Returns an iterator of all keys in arbitrary order.
+	pub fn keys(&self)->Keys<'_,K,V>
+	{
+		Keys{inner:self.iter()}
+	}
+
+	/// Returns an iterator of all values in arbitrary order.
+	pub fn values(&self)->Values<'_,K,V>
+	{
+		Values{inner:self.iter()}
+	}
+
+	/// Returns an iterator of mutable references to all values in arbitrary order.
+	pub fn values_mut(&mut self)->ValuesMut<'_,K,V>
+	{
+		ValuesMut{inner:self.iter_mut()}
+	}
+
+	/// Removes all key-value pairs from the static-hash-map and returns them as an iterator. \
+	/// The static-hash-map is empty after this call, even if the iterator is not fully consumed.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let mut m:StaticHashMap<8,u32,u32>=[(1,10),(2,20)].into_iter().collect();
+	/// assert_eq!(m.drain().count(),2);
+	/// assert!(m.is_empty());
+	/// ```
+	pub fn drain(&mut self)->Drain<'_,K,V>
+	{
+		let remaining=mem::replace(&mut self.length,0);
+		Drain
+		{
+			slots:self.slots.iter_mut(),
+			remaining
+		}
+	}
+
+	/// Removes all key-value pairs from the static-hash-map.
+	pub fn clear(&mut self)
+	{
+		self.drain();
+	}
+}
+
+impl<const N:usize,K:Hash+Eq,V,S:BuildHasher> StaticHashMap<N,K,V,S>
+{
+	fn hash_of<Q:?Sized+Hash>(&self,key:&Q)->usize
+	{
+		self.hasher.hash_one(key) as usize
+	}
+
+	// It is required that `N>0`.
+	const fn next(index:usize)->usize
+	{
+		if index+1==N {0} else {index+1}
+	}
+
+	fn probe<Q:?Sized+Hash+Eq>(&self,hash:usize,key:&Q)->Probe where K:Borrow<Q>
+	{
+		if N==0
+		{
+			return Probe::Full;
+		}
+		let mut i=hash%N;
+		for _ in 0..N
+		{
+			match &self.slots[i]
+			{
+				None=>return Probe::Vacant(i),
+				Some(b) if b.hash==hash && b.key.borrow()==key=>return Probe::Found(i),
+				Some(_)=>i=Self::next(i)
+			}
+		}
+		Probe::Full
+	}
+
+	fn find<Q:?Sized+Hash+Eq>(&self,key:&Q)->Option<usize> where K:Borrow<Q>
+	{
+		match self.probe(self.hash_of(key),key)
+		{
+			Probe::Found(i)=>Some(i),
+			_=>None
+		}
+	}
+
+	// Removes the bucket at `index`, then shifts the following buckets of the cluster backward to fill the hole.
+	fn remove_at(&mut self,index:usize)->Bucket<K,V>
+	{
+		let removed=self.slots[index].take().unwrap();
+		self.length-=1;
+		let mut hole=index;
+		let mut i=Self::next(index);
+		while let Some(b)=&self.slots[i]
+		{
+			let home=b.hash%N;
+			// The bucket can be moved to the hole only if its home is not within `(hole,i]`.
+			let stay=if hole<=i {hole<home && home<=i} else {hole<home || home<=i};
+			if !stay
+			{
+				self.slots[hole]=self.slots[i].take();
+				hole=i;
+			}
+			i=Self::next(i);
+		}
+		removed
+	}
+
+	/// Returns a reference to the value corresponding to the key.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let mut m:StaticHashMap<8,u32,&str>=StaticHashMap::new();
+	/// m.insert(1,"a").unwrap();
+	/// assert_eq!(m.get(&1),Some(&"a"));
+	/// assert_eq!(m.get(&2),None);
+	/// ```
+	pub fn get<Q:?Sized+Hash+Eq>(&self,key:&Q)->Option<&V> where K:Borrow<Q>
+	{
+		self.get_key_value(key).map(|(_,v)| v)
+	}
+
+	/// Returns the key-value pair corresponding to the supplied key.
+	pub fn get_key_value<Q:?Sized+Hash+Eq>(&self,key:&Q)->Option<(&K,&V)> where K:Borrow<Q>
+	{
+		let i=self.find(key)?;
+		self.slots[i].as_ref().map(|b| (&b.key,&b.value))
+	}
+
+	/// Returns a mutable reference to the value corresponding to the key.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let mut m:StaticHashMap<8,u32,u32>=StaticHashMap::new();
+	/// m.insert(1,10).unwrap();
+	/// *m.get_mut(&1).unwrap()+=1;
+	/// assert_eq!(m[&1],11);
+	/// ```
+	pub fn get_mut<Q:?Sized+Hash+Eq>(&mut self,key:&Q)->Option<&mut V> where K:Borrow<Q>
+	{
+		let i=self.find(key)?;
+		self.slots[i].as_mut().map(|b| &mut b.value)
+	}
+
+	/// Checks if the static-hash-map contains a value for the specified key.
+	pub fn contains_key<Q:?Sized+Hash+Eq>(&self,key:&Q)->bool where K:Borrow<Q>
+	{
+		self.find(key).is_some()
+	}
+
+	/// Inserts a key-value pair into the static-hash-map. \
+	/// Returns `Ok(None)` if the key did not exist. \
+	/// Returns `Ok(Some(V))` with the old value if the key existed. In this case, the key is not updated. \
+	/// Returns `Err(CapacityError<(K,V)>)` with the key-value pair if the key did not exist and the static-hash-map is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let mut m:StaticHashMap<1,u32,u32>=StaticHashMap::new();
+	/// assert_eq!(m.insert(1,10),Ok(None));
+	/// assert_eq!(m.insert(1,11),Ok(Some(10)));
+	/// assert_eq!(m.insert(2,20).unwrap_err().into_inner(),(2,20));
+	/// ```
+	pub fn insert(&mut self,key:K,value:V)->Result<Option<V>,CapacityError<(K,V)>>
+	{
+		let hash=self.hash_of(&key);
+		match self.probe(hash,&key)
+		{
+			Probe::Found(i)=>Ok(self.slots[i].as_mut().map(|b| mem::replace(&mut b.value,value))),
+			Probe::Vacant(i)=>
+			{
+				self.slots[i]=Some(Bucket{hash,key,value});
+				self.length+=1;
+				Ok(None)
+			}
+			Probe::Full=>Err(CapacityError::new((key,value)))
+		}
+	}
+
+	/// Removes a key from the static-hash-map, returning the value at the key if the key existed.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let mut m:StaticHashMap<8,u32,u32>=StaticHashMap::new();
+	/// m.insert(1,10).unwrap();
+	/// assert_eq!(m.remove(&1),Some(10));
+	/// assert_eq!(m.remove(&1),None);
+	/// ```
+	pub fn remove<Q:?Sized+Hash+Eq>(&mut self,key:&Q)->Option<V> where K:Borrow<Q>
+	{
+		self.remove_entry(key).map(|(_,v)| v)
+	}
+
+	/// Removes a key from the static-hash-map, returning the stored key and value if the key existed.
+	pub fn remove_entry<Q:?Sized+Hash+Eq>(&mut self,key:&Q)->Option<(K,V)> where K:Borrow<Q>
+	{
+		let i=self.find(key)?;
+		let b=self.remove_at(i);
+		Some((b.key,b.value))
+	}
+
+	/// Gets the entry of the specified key for in-place manipulation. \
+	/// Returns `Err(CapacityError<K>)` with the key if the key does not exist and the static-hash-map is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let mut m:StaticHashMap<8,&str,u32>=StaticHashMap::new();
+	/// for w in ["a","b","a"]
+	/// {
+	///     *m.entry(w).unwrap().or_insert(0)+=1;
+	/// }
+	/// assert_eq!(m["a"],2);
+	/// assert_eq!(m["b"],1);
+	/// ```
+	pub fn entry(&mut self,key:K)->Result<Entry<'_,N,K,V,S>,CapacityError<K>>
+	{
+		let hash=self.hash_of(&key);
+		match self.probe(hash,&key)
+		{
+			Probe::Found(index)=>Ok(Entry::Occupied(OccupiedEntry{map:self,index})),
+			Probe::Vacant(index)=>Ok(Entry::Vacant(VacantEntry{map:self,hash,key,index})),
+			Probe::Full=>Err(CapacityError::new(key))
+		}
+	}
+
+	/// Retains only the key-value pairs specified by the predicate. \
+	/// In other words, remove all pairs `(k,v)` such that `f(&k,&mut v)` returns `false`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let mut m:StaticHashMap<16,u32,u32>=(0..10).map(|x| (x,x*10)).collect();
+	/// m.retain(|k,_| k%2==0);
+	/// assert_eq!(m.len(),5);
+	/// assert_eq!(m.get(&3),None);
+	/// assert_eq!(m.get(&4),Some(&40));
+	/// ```
+	pub fn retain<F:FnMut(&K,&mut V)->bool>(&mut self,mut f:F)
+	{
+		// A full static-hash-map has no empty slot, so one bucket is taken out first, and is put back at last.
+		let taken=if N>0 && self.length==N {Some(self.remove_at(N-1))} else {None};
+		// Removing a bucket shifts the following buckets of its cluster backward.
+		// Scanning from an empty slot ensures no cluster wraps around the scan, so every bucket is visited exactly once.
+		if let Some(start)=self.slots.iter().position(|s| s.is_none())
+		{
+			let mut i=Self::next(start);
+			let mut visited=1;
+			while visited<N
+			{
+				if let Some(b)=&mut self.slots[i] && !f(&b.key,&mut b.value)
+				{
+					// The next bucket of the cluster may be shifted to this slot, so visit this slot again.
+					self.remove_at(i);
+				}
+				else
+				{
+					i=Self::next(i);
+					visited+=1;
+				}
+			}
+		}
+		if let Some(mut b)=taken && f(&b.key,&mut b.value)
+		{
+			let mut i=b.hash%N;
+			while self.slots[i].is_some()
+			{
+				i=Self::next(i);
+			}
+			self.slots[i]=Some(b);
+			self.length+=1;
+		}
+	}
+}
+
+impl<const N:usize,K,V,S:Default> Default for StaticHashMap<N,K,V,S>
+{
+	fn default() -> Self
+	{
+		Self::with_hasher(S::default())
+	}
+}
+
+impl<const N:usize,K:Clone,V:Clone,S:Clone> Clone for StaticHashMap<N,K,V,S>
+{
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			length:self.length,
+			slots:self.slots.clone(),
+			hasher:self.hasher.clone()
+		}
+	}
+}
+
+impl<const N:usize,K:fmt::Debug,V:fmt::Debug,S> fmt::Debug for StaticHashMap<N,K,V,S>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<const N:usize,K:Hash+Eq,V:PartialEq,S:BuildHasher> PartialEq for StaticHashMap<N,K,V,S>
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.len()==other.len() && self.iter().all(|(k,v)| other.get(k)==Some(v))
+	}
+}
+
+impl<const N:usize,K:Hash+Eq,V:Eq,S:BuildHasher> Eq for StaticHashMap<N,K,V,S> {}
+
+impl<const N:usize,K:Hash+Eq+Borrow<Q>,Q:?Sized+Hash+Eq,V,S:BuildHasher> Index<&Q> for StaticHashMap<N,K,V,S>
+{
+	type Output = V;
+
+	fn index(&self, key: &Q) -> &Self::Output
+	{
+		match self.get(key)
+		{
+			Some(v)=>v,
+			None=>panic!("Key is not found in StaticHashMap!")
+		}
+	}
+}
+
+impl<const N:usize,K:Hash+Eq,V,S:BuildHasher> Extend<(K,V)> for StaticHashMap<N,K,V,S>
+{
+	fn extend<I: IntoIterator<Item = (K,V)>>(&mut self, iter: I)
+	{
+		for (k,v) in iter
+		{
+			if self.insert(k,v).is_err()
+			{
+				panic!("StaticHashMap buffer overflow!");
+			}
+		}
+	}
+}
+
+impl<const N:usize,K:Hash+Eq,V,S:BuildHasher+Default> FromIterator<(K,V)> for StaticHashMap<N,K,V,S>
+{
+	fn from_iter<I: IntoIterator<Item = (K,V)>>(iter: I) -> Self
+	{
+		let mut m=Self::default();
+		m.extend(iter);
+		m
+	}
+}
+
+impl<const N:usize,K,V,S> IntoIterator for StaticHashMap<N,K,V,S>
+{
+	type Item = (K,V);
+	type IntoIter = IntoIter<N,K,V>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		IntoIter
+		{
+			slots:self.slots.into_iter(),
+			remaining:self.length
+		}
+	}
+}
+
+impl<'a,const N:usize,K,V,S> IntoIterator for &'a StaticHashMap<N,K,V,S>
+{
+	type Item = (&'a K,&'a V);
+	type IntoIter = Iter<'a,K,V>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a,const N:usize,K,V,S> IntoIterator for &'a mut StaticHashMap<N,K,V,S>
+{
+	type Item = (&'a K,&'a mut V);
+	type IntoIter = IterMut<'a,K,V>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+/// A view into a single entry in a static-hash-map, which may either be vacant or occupied. \
+/// This enum is constructed from the `entry` method of `StaticHashMap`.
+pub enum Entry<'a,const N:usize,K,V,S>
+{
+	/// An occupied entry.
+	Occupied(OccupiedEntry<'a,N,K,V,S>),
+	/// A vacant entry.
+	Vacant(VacantEntry<'a,N,K,V,S>)
+}
+
+impl<'a,const N:usize,K:Hash+Eq,V,S:BuildHasher> Entry<'a,N,K,V,S>
+{
+	/// Ensures a value is in the entry by inserting `default` if empty. \
+	/// Returns a mutable reference to the value in the entry.
+	pub fn or_insert(self,default:V)->&'a mut V
+	{
+		match self
+		{
+			Self::Occupied(e)=>e.into_mut(),
+			Self::Vacant(e)=>e.insert(default)
+		}
+	}
+
+	/// Ensures a value is in the entry by inserting the result of `default` if empty. \
+	/// Returns a mutable reference to the value in the entry.
+	pub fn or_insert_with<F:FnOnce()->V>(self,default:F)->&'a mut V
+	{
+		match self
+		{
+			Self::Occupied(e)=>e.into_mut(),
+			Self::Vacant(e)=>e.insert(default())
+		}
+	}
+
+	/// Ensures a value is in the entry by inserting the default value if empty. \
+	/// Returns a mutable reference to the value in the entry.
+	pub fn or_default(self)->&'a mut V where V:Default
+	{
+		self.or_insert_with(V::default)
+	}
+
+	/// Provides in-place mutable access to an occupied entry before any potential inserts into the map.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_map::StaticHashMap;
+	/// let mut m:StaticHashMap<8,u32,u32>=StaticHashMap::new();
+	/// m.entry(1).unwrap().and_modify(|v| *v+=1).or_insert(10);
+	/// m.entry(1).unwrap().and_modify(|v| *v+=1).or_insert(10);
+	/// assert_eq!(m[&1],11);
+	/// ```
+	pub fn and_modify<F:FnOnce(&mut V)>(mut self,f:F)->Self
+	{
+		if let Self::Occupied(e)=&mut self
+		{
+			f(e.get_mut());
+		}
+		self
+	}
+
+	/// Returns a reference to the key of this entry.
+	pub fn key(&self)->&K
+	{
+		match self
+		{
+			Self::Occupied(e)=>e.key(),
+			Self::Vacant(e)=>e.key()
+		}
+	}
+}
+
+/// A view into an occupied entry in a static-hash-map. It is part of the `Entry` enum.
+pub struct OccupiedEntry<'a,const N:usize,K,V,S>
+{
+	map:&'a mut StaticHashMap<N,K,V,S>,
+	index:usize
+}
+
+impl<'a,const N:usize,K:Hash+Eq,V,S:BuildHasher> OccupiedEntry<'a,N,K,V,S>
+{
+	fn bucket(&self)->&Bucket<K,V>
+	{
+		self.map.slots[self.index].as_ref().unwrap()
+	}
+
+	fn bucket_mut(&mut self)->&mut Bucket<K,V>
+	{
+		self.map.slots[self.index].as_mut().unwrap()
+	}
+
+	/// Returns a reference to the key in the entry.
+	pub fn key(&self)->&K
+	{
+		&self.bucket().key
+	}
+
+	/// Returns a reference to the value in the entry.
+	pub fn get(&self)->&V
+	{
+		&self.bucket().value
+	}
+
+	/// Returns a mutable reference to the value in the entry.
+	pub fn get_mut(&mut self)->&mut V
+	{
+		&mut self.bucket_mut().value
+	}
+
+	/// Converts the entry into a mutable reference to the value with the lifetime of the map.
+	pub fn into_mut(self)->&'a mut V
+	{
+		&mut self.map.slots[self.index].as_mut().unwrap().value
+	}
+
+	/// Sets the value of the entry, and returns the old value.
+	pub fn insert(&mut self,value:V)->V
+	{
+		mem::replace(self.get_mut(),value)
+	}
+
+	/// Takes the value out of the entry, and returns it.
+	pub fn remove(self)->V
+	{
+		self.remove_entry().1
+	}
+
+	/// Takes the key-value pair out of the entry, and returns it.
+	pub fn remove_entry(self)->(K,V)
+	{
+		let b=self.map.remove_at(self.index);
+		(b.key,b.value)
+	}
+}
+
+/// A view into a vacant entry in a static-hash-map. It is part of the `Entry` enum.
+pub struct VacantEntry<'a,const N:usize,K,V,S>
+{
+	map:&'a mut StaticHashMap<N,K,V,S>,
+	hash:usize,
+	key:K,
+	index:usize
+}
+
+impl<'a,const N:usize,K,V,S> VacantEntry<'a,N,K,V,S>
+{
+	/// Returns a reference to the key that would be used when inserting a value through the entry.
+	pub fn key(&self)->&K
+	{
+		&self.key
+	}
+
+	/// Takes the ownership of the key.
+	pub fn into_key(self)->K
+	{
+		self.key
+	}
+
+	/// Sets the value of the entry with the key of this entry, and returns a mutable reference to it.
+	pub fn insert(self,value:V)->&'a mut V
+	{
+		self.map.length+=1;
+		let b=self.map.slots[self.index].insert(Bucket{hash:self.hash,key:self.key,value});
+		&mut b.value
+	}
+}
+
+/// Immutable static-hash-map iterator.
+pub struct Iter<'a,K,V>
+{
+	slots:slice::Iter<'a,Option<Bucket<K,V>>>,
+	remaining:usize
+}
+
+impl<'a,K,V> Iterator for Iter<'a,K,V>
+{
+	type Item = (&'a K,&'a V);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let b=self.slots.find_map(|s| s.as_ref())?;
+		self.remaining-=1;
+		Some((&b.key,&b.value))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for Iter<'a,K,V> {}
+impl<'a,K,V> FusedIterator for Iter<'a,K,V> {}
+
+impl<'a,K,V> Clone for Iter<'a,K,V>
+{
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			slots:self.slots.clone(),
+			remaining:self.remaining
+		}
+	}
+}
+
+/// Mutable static-hash-map iterator.
+pub struct IterMut<'a,K,V>
+{
+	slots:slice::IterMut<'a,Option<Bucket<K,V>>>,
+	remaining:usize
+}
+
+impl<'a,K,V> Iterator for IterMut<'a,K,V>
+{
+	type Item = (&'a K,&'a mut V);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let b=self.slots.find_map(|s| s.as_mut())?;
+		self.remaining-=1;
+		Some((&b.key,&mut b.value))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for IterMut<'a,K,V> {}
+impl<'a,K,V> FusedIterator for IterMut<'a,K,V> {}
+
+/// An iterator of the keys of a static-hash-map.
+pub struct Keys<'a,K,V>
+{
+	inner:Iter<'a,K,V>
+}
+
+impl<'a,K,V> Iterator for Keys<'a,K,V>
+{
+	type Item = &'a K;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(k,_)| k)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for Keys<'a,K,V> {}
+impl<'a,K,V> FusedIterator for Keys<'a,K,V> {}
+
+/// An iterator of the values of a static-hash-map.
+pub struct Values<'a,K,V>
+{
+	inner:Iter<'a,K,V>
+}
+
+impl<'a,K,V> Iterator for Values<'a,K,V>
+{
+	type Item = &'a V;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(_,v)| v)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for Values<'a,K,V> {}
+impl<'a,K,V> FusedIterator for Values<'a,K,V> {}
+
+/// A mutable iterator of the values of a static-hash-map.
+pub struct ValuesMut<'a,K,V>
+{
+	inner:IterMut<'a,K,V>
+}
+
+impl<'a,K,V> Iterator for ValuesMut<'a,K,V>
+{
+	type Item = &'a mut V;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(_,v)| v)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for ValuesMut<'a,K,V> {}
+impl<'a,K,V> FusedIterator for ValuesMut<'a,K,V> {}
+
+/// Owning static-hash-map iterator.
+pub struct IntoIter<const N:usize,K,V>
+{
+	slots:core::array::IntoIter<Option<Bucket<K,V>>,N>,
+	remaining:usize
+}
+
+impl<const N:usize,K,V> Iterator for IntoIter<N,K,V>
+{
+	type Item = (K,V);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let b=self.slots.find_map(|s| s)?;
+		self.remaining-=1;
+		Some((b.key,b.value))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<const N:usize,K,V> ExactSizeIterator for IntoIter<N,K,V> {}
+impl<const N:usize,K,V> FusedIterator for IntoIter<N,K,V> {}
+
+/// A draining iterator of a static-hash-map. \
+/// This struct is created by the `drain` method of `StaticHashMap`.
+pub struct Drain<'a,K,V>
+{
+	slots:slice::IterMut<'a,Option<Bucket<K,V>>>,
+	remaining:usize
+}
+
+impl<'a,K,V> Iterator for Drain<'a,K,V>
+{
+	type Item = (K,V);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let b=self.slots.find_map(|s| s.take())?;
+		self.remaining-=1;
+		Some((b.key,b.value))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for Drain<'a,K,V> {}
+impl<'a,K,V> FusedIterator for Drain<'a,K,V> {}
+
+impl<'a,K,V> Drop for Drain<'a,K,V>
+{
+	fn drop(&mut self)
+	{
+		self.for_each(drop);
+	}
+}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::{collections::HashMap, vec::Vec};
+	use core::hash::BuildHasher;
+	use crate::{hash_map::StaticHashMap, Identity, xorshift};
+
+	// A hasher builder that maps every key to the same bucket, so that the cluster is as long as possible.
+	#[derive(Default)]
+	struct Collide;
+
+	impl BuildHasher for Collide
+	{
+		type Hasher = Collide;
+
+		fn build_hasher(&self) -> Self::Hasher
+		{
+			Collide
+		}
+	}
+
+	impl core::hash::Hasher for Collide
+	{
+		fn write(&mut self, _bytes: &[u8]) {}
+
+		fn write_u32(&mut self, _i: u32) {}
+
+		fn finish(&self) -> u64
+		{
+			13
+		}
+	}
+
+	#[test] fn random_ops()
+	{
+		let mut state=0x2545_F491_4F6C_DD1D;
+		let mut m:StaticHashMap<16,u32,u32>=StaticHashMap::new();
+		let mut c:StaticHashMap<16,u32,u32,Collide>=StaticHashMap::default();
+		let mut model:Vec<(u32,u32)>=Vec::new();
+		for _ in 0..20000
+		{
+			let r=xorshift(&mut state);
+			let k=(r%24) as u32;
+			let v=(r>>32) as u32;
+			match (r>>8)%4
+			{
+				0|1=>
+				{
+					let expected=match model.iter().position(|(mk,_)| *mk==k)
+					{
+						Some(i)=>Ok(Some(core::mem::replace(&mut model[i].1,v))),
+						None if model.len()<16=>
+						{
+							model.push((k,v));
+							Ok(None)
+						}
+						None=>Err(())
+					};
+					assert_eq!(m.insert(k,v).map_err(|_| ()),expected);
+					assert_eq!(c.insert(k,v).map_err(|_| ()),expected);
+				}
+				2=>
+				{
+					let expected=model.iter().position(|(mk,_)| *mk==k).map(|i| model.swap_remove(i).1);
+					assert_eq!(m.remove(&k),expected);
+					assert_eq!(c.remove(&k),expected);
+				}
+				_=>
+				{
+					let p=(r>>16) as u32%3;
+					model.retain(|(mk,_)| mk%3!=p);
+					m.retain(|k,_| k%3!=p);
+					c.retain(|k,_| k%3!=p);
+				}
+			}
+			assert_eq!(m.len(),model.len());
+			assert_eq!(c.len(),model.len());
+			for (k,v) in model.iter()
+			{
+				assert_eq!(m.get(k),Some(v));
+				assert_eq!(c.get(k),Some(v));
+			}
+		}
+	}
+
+	#[test] fn retain_wrapped_cluster()
+	{
+		// Homes 5,5,6,6 fill the slots 5,6,7,0.
+		let mut m:StaticHashMap<8,u32,u32,Identity>=StaticHashMap::default();
+		for k in [5,13,6,14]
+		{
+			m.insert(k,k).unwrap();
+		}
+		m.retain(|k,_| *k>8);
+		assert_eq!(m.len(),2);
+		assert_eq!(m.get(&13),Some(&13));
+		assert_eq!(m.get(&14),Some(&14));
+		// A full static-hash-map has no empty slot to start from.
+		let mut m:StaticHashMap<4,u32,u32,Identity>=StaticHashMap::default();
+		for k in [3,7,11,15]
+		{
+			m.insert(k,k).unwrap();
+		}
+		let mut calls=0;
+		m.retain(|k,_| {calls+=1; k%8==3});
+		assert_eq!(calls,4);
+		assert_eq!(m.len(),2);
+		assert_eq!(m.get(&3),Some(&3));
+		assert_eq!(m.get(&11),Some(&11));
+	}
+
+	#[test] fn retain_against_model()
+	{
+		let mut state=0x9E37_79B9_7F4A_7C15;
+		for _ in 0..2000
+		{
+			let mut m:StaticHashMap<16,u32,u32,Identity>=StaticHashMap::default();
+			let mut model:HashMap<u32,u32>=HashMap::new();
+			let count=xorshift(&mut state)%17;
+			while (model.len() as u64)<count
+			{
+				// Most homes are near the end of the slots, so that clusters wrap around.
+				let r=xorshift(&mut state);
+				let home=if r&1==0 {r%16} else {12+r%4};
+				let k=(home+((r>>8)%256)*16) as u32;
+				assert_eq!(m.insert(k,k).ok().unwrap(),model.insert(k,k));
+			}
+			let mask=xorshift(&mut state);
+			m.retain(|k,_| mask>>(k%64)&1==1);
+			model.retain(|k,_| mask>>(k%64)&1==1);
+			assert_eq!(m.len(),model.len());
+			for (k,v) in model.iter()
+			{
+				assert_eq!(m.get(k),Some(v));
+			}
+		}
+	}
+}
@@ -0,0 +1,558 @@
+// The static-hash-set module
+
+use core::{borrow::Borrow, fmt, hash::{BuildHasher, Hash}, iter::{Chain, FusedIterator}};
+
+use crate::{hash_map::{self, FxBuildHasher, StaticHashMap}, vec::{CapacityError, StaticVec}};
+
+/// The `StaticHashSet` type is a fixed-capacity hash set which can hold `N` values at most. \
+/// It is implemented as a `StaticHashMap` whose values are `()`.
+/// 
+/// # Example
+/// ```
+/// use static_collections::hash_set::StaticHashSet;
+/// let mut s:StaticHashSet<8,u32>=StaticHashSet::new();
+/// assert_eq!(s.insert(1),Ok(true));
+/// assert_eq!(s.insert(1),Ok(false));
+/// assert!(s.contains(&1));
+/// assert!(s.remove(&1));
+/// assert!(s.is_empty());
+/// ```
+pub struct StaticHashSet<const N:usize,T,S=FxBuildHasher>
+{
+	map:StaticHashMap<N,T,(),S>
+}
+
+impl<const N:usize,T> StaticHashSet<N,T,FxBuildHasher>
+{
+	/// Constructs a new, empty `StaticHashSet` with the default `FxBuildHasher`.
+	pub const fn new()->Self
+	{
+		Self::with_hasher(FxBuildHasher)
+	}
+}
+
+impl<const N:usize,T,S> StaticHashSet<N,T,S>
+{
+	/// Constructs a new, empty `StaticHashSet` which will use the given hasher builder to hash values.
+	pub const fn with_hasher(hasher:S)->Self
+	{
+		Self
+		{
+			map:StaticHashMap::with_hasher(hasher)
+		}
+	}
+
+	/// Returns the number of values in the static-hash-set.
+	pub const fn len(&self)->usize
+	{
+		self.map.len()
+	}
+
+	/// Checks if the static-hash-set is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.map.is_empty()
+	}
+
+	/// Checks if the static-hash-set is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.map.is_full()
+	}
+
+	/// Returns the number of values the static-hash-set can hold.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Returns a reference to the hasher builder of the static-hash-set.
+	pub const fn hasher(&self)->&S
+	{
+		self.map.hasher()
+	}
+
+	/// Returns an iterator of all values in arbitrary order.
+	pub fn iter(&self)->Iter<'_,T>
+	{
+		Iter{inner:self.map.keys()}
+	}
+
+	/// Removes all values from the static-hash-set and returns them as an iterator.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_set::StaticHashSet;
+	/// let mut s:StaticHashSet<8,u32>=[1,2,3].into_iter().collect();
+	/// assert_eq!(s.drain().sum::<u32>(),6);
+	/// assert!(s.is_empty());
+	/// ```
+	pub fn drain(&mut self)->Drain<'_,T>
+	{
+		Drain{inner:self.map.drain()}
+	}
+
+	/// Removes all values from the static-hash-set.
+	pub fn clear(&mut self)
+	{
+		self.map.clear();
+	}
+}
+
+impl<const N:usize,T:Hash+Eq,S:BuildHasher> StaticHashSet<N,T,S>
+{
+	/// Checks if the static-hash-set contains the value.
+	pub fn contains<Q:?Sized+Hash+Eq>(&self,value:&Q)->bool where T:Borrow<Q>
+	{
+		self.map.contains_key(value)
+	}
+
+	/// Returns a reference to the value in the static-hash-set that is equal to the given value.
+	pub fn get<Q:?Sized+Hash+Eq>(&self,value:&Q)->Option<&T> where T:Borrow<Q>
+	{
+		self.map.get_key_value(value).map(|(k,_)| k)
+	}
+
+	/// Adds a value to the static-hash-set. \
+	/// Returns `Ok(true)` if the value did not exist. \
+	/// Returns `Ok(false)` if the value existed. In this case, the set is not modified. \
+	/// Returns `Err(CapacityError<T>)` with the value if the value did not exist and the static-hash-set is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_set::StaticHashSet;
+	/// let mut s:StaticHashSet<1,u32>=StaticHashSet::new();
+	/// assert_eq!(s.insert(1),Ok(true));
+	/// assert_eq!(s.insert(1),Ok(false));
+	/// assert_eq!(s.insert(2).unwrap_err().into_inner(),2);
+	/// ```
+	pub fn insert(&mut self,value:T)->Result<bool,CapacityError<T>>
+	{
+		match self.map.insert(value,())
+		{
+			Ok(old)=>Ok(old.is_none()),
+			Err(e)=>Err(CapacityError::new(e.into_inner().0))
+		}
+	}
+
+	/// Removes a value from the static-hash-set. Returns whether the value existed.
+	pub fn remove<Q:?Sized+Hash+Eq>(&mut self,value:&Q)->bool where T:Borrow<Q>
+	{
+		self.map.remove(value).is_some()
+	}
+
+	/// Removes and returns the value in the static-hash-set that is equal to the given value.
+	pub fn take<Q:?Sized+Hash+Eq>(&mut self,value:&Q)->Option<T> where T:Borrow<Q>
+	{
+		self.map.remove_entry(value).map(|(k,_)| k)
+	}
+
+	/// Retains only the values specified by the predicate. \
+	/// In other words, remove all values `v` such that `f(&v)` returns `false`.
+	pub fn retain<F:FnMut(&T)->bool>(&mut self,mut f:F)
+	{
+		self.map.retain(|k,_| f(k));
+	}
+
+	/// Returns an iterator of the values in `self` but not in `other`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_set::StaticHashSet;
+	/// let a:StaticHashSet<8,u32>=[1,2,3].into_iter().collect();
+	/// let b:StaticHashSet<8,u32>=[2,3,4].into_iter().collect();
+	/// assert!(a.difference(&b).eq([1].iter()));
+	/// ```
+	pub fn difference<'a>(&'a self,other:&'a Self)->Difference<'a,N,T,S>
+	{
+		Difference{iter:self.iter(),other}
+	}
+
+	/// Returns an iterator of the values in `self` or in `other`, but not in both.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_set::StaticHashSet;
+	/// let a:StaticHashSet<8,u32>=[1,2,3].into_iter().collect();
+	/// let b:StaticHashSet<8,u32>=[2,3,4].into_iter().collect();
+	/// assert_eq!(a.symmetric_difference(&b).sum::<u32>(),5);
+	/// ```
+	pub fn symmetric_difference<'a>(&'a self,other:&'a Self)->SymmetricDifference<'a,N,T,S>
+	{
+		SymmetricDifference{inner:self.difference(other).chain(other.difference(self))}
+	}
+
+	/// Returns an iterator of the values in both `self` and `other`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_set::StaticHashSet;
+	/// let a:StaticHashSet<8,u32>=[1,2,3].into_iter().collect();
+	/// let b:StaticHashSet<8,u32>=[2,3,4].into_iter().collect();
+	/// assert_eq!(a.intersection(&b).sum::<u32>(),5);
+	/// ```
+	pub fn intersection<'a>(&'a self,other:&'a Self)->Intersection<'a,N,T,S>
+	{
+		Intersection{iter:self.iter(),other}
+	}
+
+	/// Returns an iterator of the values in `self` or in `other`, without duplicates.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::hash_set::StaticHashSet;
+	/// let a:StaticHashSet<8,u32>=[1,2,3].into_iter().collect();
+	/// let b:StaticHashSet<8,u32>=[2,3,4].into_iter().collect();
+	/// assert_eq!(a.union(&b).sum::<u32>(),10);
+	/// ```
+	pub fn union<'a>(&'a self,other:&'a Self)->Union<'a,N,T,S>
+	{
+		Union{inner:self.iter().chain(other.difference(self))}
+	}
+
+	/// Checks if `self` has no values in common with `other`.
+	pub fn is_disjoint(&self,other:&Self)->bool
+	{
+		self.iter().all(|v| !other.contains(v))
+	}
+
+	/// Checks if all values of `self` are in `other`.
+	pub fn is_subset(&self,other:&Self)->bool
+	{
+		self.len()<=other.len() && self.iter().all(|v| other.contains(v))
+	}
+
+	/// Checks if all values of `other` are in `self`.
+	pub fn is_superset(&self,other:&Self)->bool
+	{
+		other.is_subset(self)
+	}
+}
+
+impl<const N:usize,T,S:Default> Default for StaticHashSet<N,T,S>
+{
+	fn default() -> Self
+	{
+		Self::with_hasher(S::default())
+	}
+}
+
+impl<const N:usize,T:Clone,S:Clone> Clone for StaticHashSet<N,T,S>
+{
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			map:self.map.clone()
+		}
+	}
+}
+
+impl<const N:usize,T:fmt::Debug,S> fmt::Debug for StaticHashSet<N,T,S>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<const N:usize,T:Hash+Eq,S:BuildHasher> PartialEq for StaticHashSet<N,T,S>
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.map==other.map
+	}
+}
+
+impl<const N:usize,T:Hash+Eq,S:BuildHasher> Eq for StaticHashSet<N,T,S> {}
+
+impl<const N:usize,T:Hash+Eq,S:BuildHasher> Extend<T> for StaticHashSet<N,T,S>
+{
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+	{
+		for v in iter
+		{
+			if self.insert(v).is_err()
+			{
+				panic!("StaticHashSet buffer overflow!");
+			}
+		}
+	}
+}
+
+impl<const N:usize,T:Hash+Eq,S:BuildHasher+Default> FromIterator<T> for StaticHashSet<N,T,S>
+{
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self
+	{
+		let mut s=Self::default();
+		s.extend(iter);
+		s
+	}
+}
+
+/// Collects the values of a `StaticVec` into a `StaticHashSet`. Duplicated values are dropped. \
+/// This conversion never overflows, because there are at most `N` distinct values.
+/// 
+/// # Example
+/// ```
+/// use static_collections::{hash_set::StaticHashSet,vec::StaticVec};
+/// let v:StaticVec<4,u32>=[1,2,2,3].into_iter().collect();
+/// let s:StaticHashSet<4,u32>=StaticHashSet::from(v);
+/// assert_eq!(s.len(),3);
+/// ```
+impl<const N:usize,T:Hash+Eq,S:BuildHasher+Default> From<StaticVec<N,T>> for StaticHashSet<N,T,S>
+{
+	fn from(value: StaticVec<N,T>) -> Self
+	{
+		value.into_iter().collect()
+	}
+}
+
+/// Moves the values of a `StaticHashSet` into a `StaticVec` in arbitrary order.
+impl<const N:usize,T,S> From<StaticHashSet<N,T,S>> for StaticVec<N,T>
+{
+	fn from(value: StaticHashSet<N,T,S>) -> Self
+	{
+		value.into_iter().collect()
+	}
+}
+
+impl<const N:usize,T,S> IntoIterator for StaticHashSet<N,T,S>
+{
+	type Item = T;
+	type IntoIter = IntoIter<N,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		IntoIter{inner:self.map.into_iter()}
+	}
+}
+
+impl<'a,const N:usize,T,S> IntoIterator for &'a StaticHashSet<N,T,S>
+{
+	type Item = &'a T;
+	type IntoIter = Iter<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+/// Immutable static-hash-set iterator.
+pub struct Iter<'a,T>
+{
+	inner:hash_map::Keys<'a,T,()>
+}
+
+impl<'a,T> Iterator for Iter<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,T> ExactSizeIterator for Iter<'a,T> {}
+impl<'a,T> FusedIterator for Iter<'a,T> {}
+
+/// Owning static-hash-set iterator.
+pub struct IntoIter<const N:usize,T>
+{
+	inner:hash_map::IntoIter<N,T,()>
+}
+
+impl<const N:usize,T> Iterator for IntoIter<N,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(k,_)| k)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<const N:usize,T> ExactSizeIterator for IntoIter<N,T> {}
+impl<const N:usize,T> FusedIterator for IntoIter<N,T> {}
+
+/// A draining iterator of a static-hash-set. \
+/// This struct is created by the `drain` method of `StaticHashSet`.
+pub struct Drain<'a,T>
+{
+	inner:hash_map::Drain<'a,T,()>
+}
+
+impl<'a,T> Iterator for Drain<'a,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(k,_)| k)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,T> ExactSizeIterator for Drain<'a,T> {}
+impl<'a,T> FusedIterator for Drain<'a,T> {}
+
+/// A lazy iterator of the values in the intersection of two static-hash-sets.
+pub struct Intersection<'a,const N:usize,T,S>
+{
+	iter:Iter<'a,T>,
+	other:&'a StaticHashSet<N,T,S>
+}
+
+impl<'a,const N:usize,T:Hash+Eq,S:BuildHasher> Iterator for Intersection<'a,N,T,S>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let other=self.other;
+		self.iter.find(|v| other.contains(*v))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(0,self.iter.size_hint().1)
+	}
+}
+
+impl<'a,const N:usize,T:Hash+Eq,S:BuildHasher> FusedIterator for Intersection<'a,N,T,S> {}
+
+/// A lazy iterator of the values in the difference of two static-hash-sets.
+pub struct Difference<'a,const N:usize,T,S>
+{
+	iter:Iter<'a,T>,
+	other:&'a StaticHashSet<N,T,S>
+}
+
+impl<'a,const N:usize,T:Hash+Eq,S:BuildHasher> Iterator for Difference<'a,N,T,S>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let other=self.other;
+		self.iter.find(|v| !other.contains(*v))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(0,self.iter.size_hint().1)
+	}
+}
+
+impl<'a,const N:usize,T:Hash+Eq,S:BuildHasher> FusedIterator for Difference<'a,N,T,S> {}
+
+/// A lazy iterator of the values in the symmetric difference of two static-hash-sets.
+pub struct SymmetricDifference<'a,const N:usize,T,S>
+{
+	inner:Chain<Difference<'a,N,T,S>,Difference<'a,N,T,S>>
+}
+
+impl<'a,const N:usize,T:Hash+Eq,S:BuildHasher> Iterator for SymmetricDifference<'a,N,T,S>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,const N:usize,T:Hash+Eq,S:BuildHasher> FusedIterator for SymmetricDifference<'a,N,T,S> {}
+
+/// A lazy iterator of the values in the union of two static-hash-sets.
+pub struct Union<'a,const N:usize,T,S>
+{
+	inner:Chain<Iter<'a,T>,Difference<'a,N,T,S>>
+}
+
+impl<'a,const N:usize,T:Hash+Eq,S:BuildHasher> Iterator for Union<'a,N,T,S>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,const N:usize,T:Hash+Eq,S:BuildHasher> FusedIterator for Union<'a,N,T,S> {}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::vec::Vec;
+	use crate::{hash_set::StaticHashSet, Identity};
+
+	fn sorted<'a>(i:impl Iterator<Item=&'a u32>)->Vec<u32>
+	{
+		let mut v:Vec<u32>=i.copied().collect();
+		v.sort();
+		v
+	}
+
+	#[test] fn retain()
+	{
+		// Homes 5,5,6,6 fill the slots 5,6,7,0.
+		let mut s:StaticHashSet<8,u32,Identity>=[5,13,6,14].into_iter().collect();
+		s.retain(|v| *v>8);
+		assert_eq!(sorted(s.iter()),[13,14]);
+		assert!(s.contains(&13));
+		assert!(s.contains(&14));
+		// Retaining in a full set.
+		let mut s:StaticHashSet<4,u32,Identity>=[3,7,11,15].into_iter().collect();
+		s.retain(|v| *v!=7);
+		assert_eq!(sorted(s.iter()),[3,11,15]);
+		assert!([3,11,15].iter().all(|v| s.contains(v)));
+	}
+
+	#[test] fn set_operations()
+	{
+		let a:StaticHashSet<8,u32,Identity>=[1,2,3,9].into_iter().collect();
+		let b:StaticHashSet<8,u32,Identity>=[2,3,4,10].into_iter().collect();
+		let c:StaticHashSet<8,u32,Identity>=[2,3].into_iter().collect();
+		assert_eq!(sorted(a.union(&b)),[1,2,3,4,9,10]);
+		assert_eq!(sorted(a.intersection(&b)),[2,3]);
+		assert_eq!(sorted(a.difference(&b)),[1,9]);
+		assert_eq!(sorted(a.symmetric_difference(&b)),[1,4,9,10]);
+		assert!(c.is_subset(&a));
+		assert!(a.is_superset(&c));
+		assert!(!a.is_subset(&b));
+		assert!(!a.is_disjoint(&b));
+		assert!(a.difference(&b).all(|v| !b.contains(v)));
+		let d:StaticHashSet<8,u32,Identity>=[5,6].into_iter().collect();
+		assert!(a.is_disjoint(&d));
+		assert_eq!(sorted(a.intersection(&d)),[]);
+	}
+}
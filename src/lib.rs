@@ -16,10 +16,124 @@ pub mod bitmap;
 /// This module contains the `StaticVec` type.
 pub mod vec;
 
+/// A growable array type which holds `N` values inline and spills into a caller-provided buffer, written as
+/// `StaticSmallVec<'a,N,T>`.
+/// 
+/// This module contains the `StaticSmallVec` type.
+pub mod small_vec;
+
+/// A growable array type over a borrowed buffer of uninitialized memory, written as
+/// `SliceVec<'a,T>`.
+/// 
+/// This module contains the `SliceVec` type.
+pub mod slice_vec;
+
+/// A double-ended queue implemented with a fixed-capacity ring buffer, written as
+/// `StaticDeque<N,T>`.
+/// 
+/// This module contains the `StaticDeque` type.
+pub mod deque;
+
+/// A lock-free single-producer single-consumer queue.
+/// 
+/// This module contains the `Queue` type, and its `Producer` and `Consumer` handles.
+pub mod spsc;
+
+/// A hash map implemented with open addressing in a fixed-capacity array, written as
+/// `StaticHashMap<N,K,V,S>`.
+/// 
+/// This module contains the `StaticHashMap` type, and the `FxHasher` used by default.
+pub mod hash_map;
+
+/// An ordered map implemented with a sorted fixed-capacity array, written as
+/// `StaticBTreeMap<N,K,V>`.
+/// 
+/// This module contains the `StaticBTreeMap` type.
+pub mod btree_map;
+
+/// A hash set implemented as a `StaticHashMap` without values, written as
+/// `StaticHashSet<N,T,S>`.
+/// 
+/// This module contains the `StaticHashSet` type.
+pub mod hash_set;
+
+/// An ordered set implemented as a `StaticBTreeMap` without values, written as
+/// `StaticBTreeSet<N,T>`.
+/// 
+/// This module contains the `StaticBTreeSet` type.
+pub mod btree_set;
+
+/// A priority queue implemented with a binary heap in a fixed-capacity array, written as
+/// `StaticBinaryHeap<N,T>`.
+/// 
+/// This module contains the `StaticBinaryHeap` type.
+pub mod binary_heap;
+
+/// An object pool with stable handles, implemented with a fixed-capacity array, written as
+/// `StaticSlab<N,T>`.
+/// 
+/// This module contains the `StaticSlab` type.
+pub mod slab;
+
+/// A generational arena with checked handles, implemented with a fixed-capacity array, written as
+/// `StaticArena<N,T>`.
+/// 
+/// This module contains the `StaticArena` type.
+pub mod arena;
+
+/// A doubly-linked list with nodes linked by indices in a fixed-capacity array, written as
+/// `StaticLinkedList<N,T>`.
+/// 
+/// This module contains the `StaticLinkedList` type, and its `CursorMut` type.
+pub mod linked_list;
+
+/// A least-recently-used cache composed of a `StaticHashMap` and a `StaticLinkedList`, written as
+/// `StaticLruCache<N,K,V>`.
+/// 
+/// This module contains the `StaticLruCache` type.
+pub mod lru;
+
 /// Utilities related to FFI bindings.
 /// 
 /// This module contains utilities to handle data across non-Rust interfaces,
 /// like other programming languages and the underlying operating system. It is
 /// mainly of use for FFI (Foreign Function Interface) bindings and code that
 /// needs to exchange C-like strings with other languages.
-pub mod ffi;
\ No newline at end of file
+pub mod ffi;
+
+// A xorshift pseudo-random generator for reproducible randomized tests.
+#[cfg(test)] pub(crate) fn xorshift(state:&mut u64)->u64
+{
+	*state^=*state<<13;
+	*state^=*state>>7;
+	*state^=*state<<17;
+	*state
+}
+
+// A hasher which uses the key itself as the hash, so that the tests can choose the home slot of each key.
+#[cfg(test)] #[derive(Default)] pub(crate) struct Identity(u64);
+
+#[cfg(test)] impl core::hash::BuildHasher for Identity
+{
+	type Hasher = Identity;
+
+	fn build_hasher(&self) -> Self::Hasher
+	{
+		Identity(0)
+	}
+}
+
+#[cfg(test)] impl core::hash::Hasher for Identity
+{
+	fn write(&mut self, _bytes: &[u8]) {}
+
+	fn write_u32(&mut self, i: u32)
+	{
+		self.0=i as u64;
+	}
+
+	fn finish(&self) -> u64
+	{
+		self.0
+	}
+}
@@ -0,0 +1,957 @@
+// The static-linked-list module
+
+use core::{fmt, hash::{Hash, Hasher}, iter::FusedIterator, marker::PhantomData, mem};
+
+use crate::vec::{CapacityError, StaticVec};
+
+// The link to the "ghost" node, which is both before the head and after the tail.
+const NIL:usize=usize::MAX;
+
+enum Node<T>
+{
+	Occupied{value:T,prev:usize,next:usize},
+	// A vacant node holds the index of the next vacant node.
+	Vacant(usize)
+}
+
+/// The `StaticLinkedList` type is a fixed-capacity doubly-linked list which can hold `N` values at most. \
+/// Nodes live in an internal array and are linked by indices, so no heap allocation is involved. \
+/// The index of a node stays the same while the value is in the list.
+/// 
+/// Use `CursorMut` to insert or remove values in the middle of the list in `O(1)` time.
+/// 
+/// # Example
+/// ```
+/// use static_collections::linked_list::StaticLinkedList;
+/// let mut l:StaticLinkedList<8,u64>=StaticLinkedList::new();
+/// l.push_back(2);
+/// l.push_back(3);
+/// l.push_front(1);
+/// assert!(l.iter().eq(&[1,2,3]));
+/// ```
+pub struct StaticLinkedList<const N:usize,T>
+{
+	nodes:StaticVec<N,Node<T>>,
+	head:usize,
+	tail:usize,
+	// Head of the free list. If it is `NIL`, vacant nodes must be appended to `nodes`.
+	free:usize,
+	length:usize
+}
+
+impl<const N:usize,T> Default for StaticLinkedList<N,T>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,T> StaticLinkedList<N,T>
+{
+	/// Constructs a new, empty `StaticLinkedList`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::linked_list::StaticLinkedList;
+	/// let l:StaticLinkedList<8,u64>=StaticLinkedList::new();
+	/// assert!(l.is_empty());
+	/// ```
+	pub const fn new()->Self
+	{
+		Self
+		{
+			nodes:StaticVec::new(),
+			head:NIL,
+			tail:NIL,
+			free:NIL,
+			length:0
+		}
+	}
+
+	/// Returns the number of values in the static-linked-list.
+	pub const fn len(&self)->usize
+	{
+		self.length
+	}
+
+	/// Checks if the static-linked-list is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.length==0
+	}
+
+	/// Checks if the static-linked-list is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.length==N
+	}
+
+	/// Returns the number of values the static-linked-list can hold.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	// Returns the previous node. The previous node of the ghost is the tail.
+	fn prev_of(&self,node:usize)->usize
+	{
+		if node==NIL
+		{
+			self.tail
+		}
+		else
+		{
+			match self.nodes[node]
+			{
+				Node::Occupied{prev,..}=>prev,
+				Node::Vacant(_)=>unreachable!()
+			}
+		}
+	}
+
+	// Returns the next node. The next node of the ghost is the head.
+	fn next_of(&self,node:usize)->usize
+	{
+		if node==NIL
+		{
+			self.head
+		}
+		else
+		{
+			match self.nodes[node]
+			{
+				Node::Occupied{next,..}=>next,
+				Node::Vacant(_)=>unreachable!()
+			}
+		}
+	}
+
+	fn set_prev(&mut self,node:usize,link:usize)
+	{
+		if node==NIL
+		{
+			self.tail=link;
+		}
+		else if let Node::Occupied{prev,..}=&mut self.nodes[node]
+		{
+			*prev=link;
+		}
+	}
+
+	fn set_next(&mut self,node:usize,link:usize)
+	{
+		if node==NIL
+		{
+			self.head=link;
+		}
+		else if let Node::Occupied{next,..}=&mut self.nodes[node]
+		{
+			*next=link;
+		}
+	}
+
+	pub(crate) fn value(&self,node:usize)->Option<&T>
+	{
+		match self.nodes.get(node)
+		{
+			Some(Node::Occupied{value,..})=>Some(value),
+			_=>None
+		}
+	}
+
+	pub(crate) fn value_mut(&mut self,node:usize)->Option<&mut T>
+	{
+		match self.nodes.get_mut(node)
+		{
+			Some(Node::Occupied{value,..})=>Some(value),
+			_=>None
+		}
+	}
+
+	// Takes the node out of the links, but keeps the value in it.
+	fn detach(&mut self,node:usize)
+	{
+		let (prev,next)=(self.prev_of(node),self.next_of(node));
+		self.set_next(prev,next);
+		self.set_prev(next,prev);
+	}
+
+	// Puts a detached node between `prev` and `next`, which must be adjacent.
+	fn attach(&mut self,node:usize,prev:usize,next:usize)
+	{
+		if let Node::Occupied{prev:p,next:n,..}=&mut self.nodes[node]
+		{
+			*p=prev;
+			*n=next;
+		}
+		self.set_next(prev,node);
+		self.set_prev(next,node);
+	}
+
+	// Allocates a node for `value`, and links it between `prev` and `next`, which must be adjacent.
+	fn link(&mut self,value:T,prev:usize,next:usize)->Result<usize,CapacityError<T>>
+	{
+		let node=if self.free!=NIL
+		{
+			let node=self.free;
+			let Node::Vacant(f)=mem::replace(&mut self.nodes[node],Node::Occupied{value,prev,next}) else {unreachable!("The free list of StaticLinkedList is corrupted!")};
+			self.free=f;
+			node
+		}
+		else
+		{
+			if let Err(e)=self.nodes.try_push(Node::Occupied{value,prev,next})
+			{
+				let Node::Occupied{value,..}=e.into_inner() else {unreachable!()};
+				return Err(CapacityError::new(value));
+			}
+			self.nodes.len()-1
+		};
+		self.set_next(prev,node);
+		self.set_prev(next,node);
+		self.length+=1;
+		Ok(node)
+	}
+
+	// Unlinks the node, releases it to the free list, and returns the value in it.
+	fn unlink(&mut self,node:usize)->T
+	{
+		self.detach(node);
+		let Node::Occupied{value,..}=mem::replace(&mut self.nodes[node],Node::Vacant(self.free)) else {unreachable!()};
+		self.free=node;
+		self.length-=1;
+		value
+	}
+
+	/// Put value `v` to the back of the static-linked-list.
+	/// 
+	/// # Panic
+	/// If the static-linked-list is full, this method would panic. \
+	/// Use `try_push_back` method if you would like to handle the overflow.
+	pub fn push_back(&mut self,v:T)
+	{
+		if self.try_push_back(v).is_err()
+		{
+			panic!("StaticLinkedList buffer overflow!");
+		}
+	}
+
+	/// Put value `v` to the back of the static-linked-list. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static-linked-list is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::linked_list::StaticLinkedList;
+	/// let mut l:StaticLinkedList<1,u64>=StaticLinkedList::new();
+	/// assert!(l.try_push_back(1).is_ok());
+	/// assert_eq!(l.try_push_back(2).unwrap_err().into_inner(),2);
+	/// ```
+	pub fn try_push_back(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		self.link(v,self.tail,NIL).map(|_| ())
+	}
+
+	/// Put value `v` to the front of the static-linked-list.
+	/// 
+	/// # Panic
+	/// If the static-linked-list is full, this method would panic. \
+	/// Use `try_push_front` method if you would like to handle the overflow.
+	pub fn push_front(&mut self,v:T)
+	{
+		if self.try_push_front(v).is_err()
+		{
+			panic!("StaticLinkedList buffer overflow!");
+		}
+	}
+
+	/// Put value `v` to the front of the static-linked-list. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static-linked-list is full.
+	pub fn try_push_front(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		self.link(v,NIL,self.head).map(|_| ())
+	}
+
+	/// Read and remove the value at the back of the static-linked-list.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::linked_list::StaticLinkedList;
+	/// let mut l:StaticLinkedList<8,u64>=[1,2].into_iter().collect();
+	/// assert_eq!(l.pop_back(),Some(2));
+	/// assert_eq!(l.pop_back(),Some(1));
+	/// assert_eq!(l.pop_back(),None);
+	/// ```
+	pub fn pop_back(&mut self)->Option<T>
+	{
+		if self.tail==NIL {None} else {Some(self.unlink(self.tail))}
+	}
+
+	/// Read and remove the value at the front of the static-linked-list.
+	pub fn pop_front(&mut self)->Option<T>
+	{
+		if self.head==NIL {None} else {Some(self.unlink(self.head))}
+	}
+
+	/// Returns a reference to the front value.
+	pub fn front(&self)->Option<&T>
+	{
+		self.value(self.head)
+	}
+
+	/// Returns a mutable reference to the front value.
+	pub fn front_mut(&mut self)->Option<&mut T>
+	{
+		self.value_mut(self.head)
+	}
+
+	/// Returns a reference to the back value.
+	pub fn back(&self)->Option<&T>
+	{
+		self.value(self.tail)
+	}
+
+	/// Returns a mutable reference to the back value.
+	pub fn back_mut(&mut self)->Option<&mut T>
+	{
+		self.value_mut(self.tail)
+	}
+
+	/// Checks if the static-linked-list contains a value equal to `x`.
+	pub fn contains(&self,x:&T)->bool where T:PartialEq
+	{
+		self.iter().any(|v| v==x)
+	}
+
+	/// Returns an iterator of the static-linked-list, from front to back.
+	pub fn iter(&self)->Iter<'_,T>
+	{
+		Iter
+		{
+			nodes:self.nodes.as_slice(),
+			head:self.head,
+			tail:self.tail,
+			remaining:self.length
+		}
+	}
+
+	/// Returns a mutable iterator of the static-linked-list, from front to back.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::linked_list::StaticLinkedList;
+	/// let mut l:StaticLinkedList<8,u64>=[1,2,3].into_iter().collect();
+	/// l.iter_mut().for_each(|v| *v*=10);
+	/// assert!(l.iter().eq(&[10,20,30]));
+	/// ```
+	pub fn iter_mut(&mut self)->IterMut<'_,T>
+	{
+		IterMut
+		{
+			nodes:self.nodes.as_mut_ptr(),
+			head:self.head,
+			tail:self.tail,
+			remaining:self.length,
+			phantom:PhantomData
+		}
+	}
+
+	/// Removes all values from the static-linked-list.
+	pub fn clear(&mut self)
+	{
+		self.nodes.clear();
+		self.head=NIL;
+		self.tail=NIL;
+		self.free=NIL;
+		self.length=0;
+	}
+
+	/// Returns a cursor pointing to the front value. \
+	/// If the static-linked-list is empty, the cursor points to the "ghost" non-element.
+	pub fn cursor_front_mut(&mut self)->CursorMut<'_,N,T>
+	{
+		CursorMut
+		{
+			node:self.head,
+			list:self
+		}
+	}
+
+	/// Returns a cursor pointing to the back value. \
+	/// If the static-linked-list is empty, the cursor points to the "ghost" non-element.
+	pub fn cursor_back_mut(&mut self)->CursorMut<'_,N,T>
+	{
+		CursorMut
+		{
+			node:self.tail,
+			list:self
+		}
+	}
+
+	/// Returns a cursor pointing to the value in the node at index `node`. \
+	/// Returns `None` if the node does not hold a value. \
+	/// The index of a node can be retrieved by `CursorMut::node` method.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::linked_list::StaticLinkedList;
+	/// let mut l:StaticLinkedList<8,char>=['a','b','c'].into_iter().collect();
+	/// let mut c=l.cursor_back_mut();
+	/// let node=c.node().unwrap();
+	/// c.move_to_front();
+	/// assert!(l.iter().eq(&['c','a','b']));
+	/// assert_eq!(l.cursor_mut_at(node).unwrap().remove_current(),Some('c'));
+	/// assert!(l.cursor_mut_at(node).is_none());
+	/// ```
+	pub fn cursor_mut_at(&mut self,node:usize)->Option<CursorMut<'_,N,T>>
+	{
+		match self.nodes.get(node)
+		{
+			Some(Node::Occupied{..})=>Some(CursorMut{list:self,node}),
+			_=>None
+		}
+	}
+}
+
+/// A cursor over a `StaticLinkedList` with editing operations. \
+/// The cursor points to either a value, or the "ghost" non-element between the back and the front of the list.
+/// 
+/// # Example
+/// ```
+/// use static_collections::linked_list::StaticLinkedList;
+/// let mut l:StaticLinkedList<8,u64>=[1,2,4].into_iter().collect();
+/// let mut c=l.cursor_front_mut();
+/// c.move_next();
+/// c.insert_after(3);
+/// assert_eq!(c.remove_current(),Some(2));
+/// assert_eq!(c.current(),Some(&mut 3));
+/// assert!(l.iter().eq(&[1,3,4]));
+/// ```
+pub struct CursorMut<'a,const N:usize,T>
+{
+	list:&'a mut StaticLinkedList<N,T>,
+	node:usize
+}
+
+impl<'a,const N:usize,T> CursorMut<'a,N,T>
+{
+	/// Returns the index of the node the cursor points to. \
+	/// Returns `None` if the cursor points to the "ghost" non-element.
+	pub fn node(&self)->Option<usize>
+	{
+		if self.node==NIL {None} else {Some(self.node)}
+	}
+
+	/// Returns a mutable reference to the value the cursor points to. \
+	/// Returns `None` if the cursor points to the "ghost" non-element.
+	pub fn current(&mut self)->Option<&mut T>
+	{
+		self.list.value_mut(self.node)
+	}
+
+	/// Returns a mutable reference to the next value, without moving the cursor.
+	pub fn peek_next(&mut self)->Option<&mut T>
+	{
+		self.list.value_mut(self.list.next_of(self.node))
+	}
+
+	/// Returns a mutable reference to the previous value, without moving the cursor.
+	pub fn peek_prev(&mut self)->Option<&mut T>
+	{
+		self.list.value_mut(self.list.prev_of(self.node))
+	}
+
+	/// Moves the cursor to the next value. \
+	/// If the cursor points to the back value, it moves to the "ghost" non-element. \
+	/// If the cursor points to the "ghost" non-element, it moves to the front value.
+	pub fn move_next(&mut self)
+	{
+		self.node=self.list.next_of(self.node);
+	}
+
+	/// Moves the cursor to the previous value. \
+	/// If the cursor points to the front value, it moves to the "ghost" non-element. \
+	/// If the cursor points to the "ghost" non-element, it moves to the back value.
+	pub fn move_prev(&mut self)
+	{
+		self.node=self.list.prev_of(self.node);
+	}
+
+	/// Inserts value `v` after the cursor. \
+	/// If the cursor points to the "ghost" non-element, the value is inserted at the front.
+	/// 
+	/// # Panic
+	/// If the static-linked-list is full, this method would panic. \
+	/// Use `try_insert_after` method if you would like to handle the overflow.
+	pub fn insert_after(&mut self,v:T)
+	{
+		if self.try_insert_after(v).is_err()
+		{
+			panic!("StaticLinkedList buffer overflow!");
+		}
+	}
+
+	/// Inserts value `v` after the cursor. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static-linked-list is full.
+	pub fn try_insert_after(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		let next=self.list.next_of(self.node);
+		self.list.link(v,self.node,next).map(|_| ())
+	}
+
+	/// Inserts value `v` before the cursor. \
+	/// If the cursor points to the "ghost" non-element, the value is inserted at the back.
+	/// 
+	/// # Panic
+	/// If the static-linked-list is full, this method would panic. \
+	/// Use `try_insert_before` method if you would like to handle the overflow.
+	pub fn insert_before(&mut self,v:T)
+	{
+		if self.try_insert_before(v).is_err()
+		{
+			panic!("StaticLinkedList buffer overflow!");
+		}
+	}
+
+	/// Inserts value `v` before the cursor. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static-linked-list is full.
+	pub fn try_insert_before(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		let prev=self.list.prev_of(self.node);
+		self.list.link(v,prev,self.node).map(|_| ())
+	}
+
+	/// Removes the value the cursor points to, and moves the cursor to the next value. \
+	/// Returns `None` if the cursor points to the "ghost" non-element.
+	pub fn remove_current(&mut self)->Option<T>
+	{
+		if self.node==NIL
+		{
+			None
+		}
+		else
+		{
+			let next=self.list.next_of(self.node);
+			let v=self.list.unlink(self.node);
+			self.node=next;
+			Some(v)
+		}
+	}
+
+	/// Moves the value the cursor points to to the front of the static-linked-list. \
+	/// The cursor keeps pointing to the moved value. This operation takes `O(1)` time.
+	pub fn move_to_front(&mut self)
+	{
+		if self.node!=NIL && self.node!=self.list.head
+		{
+			self.list.detach(self.node);
+			self.list.attach(self.node,NIL,self.list.head);
+		}
+	}
+
+	/// Moves the value the cursor points to to the back of the static-linked-list. \
+	/// The cursor keeps pointing to the moved value. This operation takes `O(1)` time.
+	pub fn move_to_back(&mut self)
+	{
+		if self.node!=NIL && self.node!=self.list.tail
+		{
+			self.list.detach(self.node);
+			self.list.attach(self.node,self.list.tail,NIL);
+		}
+	}
+
+	/// Moves all values of `other` after the cursor, keeping their order. \
+	/// If the cursor points to the "ghost" non-element, the values are moved to the front.
+	/// 
+	/// # Panic
+	/// If the values of `other` do not fit in the static-linked-list, this method would panic. \
+	/// Use `try_splice_after` method if you would like to handle the overflow.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::linked_list::StaticLinkedList;
+	/// let mut l:StaticLinkedList<8,u64>=[1,4].into_iter().collect();
+	/// let other:StaticLinkedList<4,u64>=[2,3].into_iter().collect();
+	/// l.cursor_front_mut().splice_after(other);
+	/// assert!(l.iter().eq(&[1,2,3,4]));
+	/// ```
+	pub fn splice_after<const M:usize>(&mut self,other:StaticLinkedList<M,T>)
+	{
+		if self.try_splice_after(other).is_err()
+		{
+			panic!("StaticLinkedList buffer overflow!");
+		}
+	}
+
+	/// Moves all values of `other` after the cursor, keeping their order. \
+	/// Returns `Err(CapacityError<StaticLinkedList<M,T>>)` with `other` untouched if its values do not fit in the static-linked-list.
+	pub fn try_splice_after<const M:usize>(&mut self,other:StaticLinkedList<M,T>)->Result<(),CapacityError<StaticLinkedList<M,T>>>
+	{
+		if self.list.length+other.length>N
+		{
+			return Err(CapacityError::new(other));
+		}
+		let mut prev=self.node;
+		for v in other
+		{
+			let next=self.list.next_of(prev);
+			prev=self.list.link(v,prev,next).ok().unwrap();
+		}
+		Ok(())
+	}
+
+	/// Moves all values of `other` before the cursor, keeping their order. \
+	/// If the cursor points to the "ghost" non-element, the values are moved to the back.
+	/// 
+	/// # Panic
+	/// If the values of `other` do not fit in the static-linked-list, this method would panic. \
+	/// Use `try_splice_before` method if you would like to handle the overflow.
+	pub fn splice_before<const M:usize>(&mut self,other:StaticLinkedList<M,T>)
+	{
+		if self.try_splice_before(other).is_err()
+		{
+			panic!("StaticLinkedList buffer overflow!");
+		}
+	}
+
+	/// Moves all values of `other` before the cursor, keeping their order. \
+	/// Returns `Err(CapacityError<StaticLinkedList<M,T>>)` with `other` untouched if its values do not fit in the static-linked-list.
+	pub fn try_splice_before<const M:usize>(&mut self,other:StaticLinkedList<M,T>)->Result<(),CapacityError<StaticLinkedList<M,T>>>
+	{
+		if self.list.length+other.length>N
+		{
+			return Err(CapacityError::new(other));
+		}
+		for v in other
+		{
+			let prev=self.list.prev_of(self.node);
+			self.list.link(v,prev,self.node).ok().unwrap();
+		}
+		Ok(())
+	}
+}
+
+impl<const N:usize,T:Clone> Clone for StaticLinkedList<N,T>
+{
+	fn clone(&self) -> Self
+	{
+		self.iter().cloned().collect()
+	}
+}
+
+impl<const N:usize,T:fmt::Debug> fmt::Debug for StaticLinkedList<N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<const N:usize,const M:usize,T:PartialEq> PartialEq<StaticLinkedList<M,T>> for StaticLinkedList<N,T>
+{
+	fn eq(&self, other: &StaticLinkedList<M,T>) -> bool
+	{
+		self.length==other.length && self.iter().eq(other.iter())
+	}
+}
+
+impl<const N:usize,T:Eq> Eq for StaticLinkedList<N,T> {}
+
+impl<const N:usize,T:Hash> Hash for StaticLinkedList<N,T>
+{
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		state.write_usize(self.length);
+		self.iter().for_each(|v| v.hash(state));
+	}
+}
+
+impl<const N:usize,T> Extend<T> for StaticLinkedList<N,T>
+{
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+	{
+		iter.into_iter().for_each(|v| self.push_back(v));
+	}
+}
+
+impl<const N:usize,T> FromIterator<T> for StaticLinkedList<N,T>
+{
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self
+	{
+		let mut l=Self::new();
+		l.extend(iter);
+		l
+	}
+}
+
+impl<const N:usize,T> IntoIterator for StaticLinkedList<N,T>
+{
+	type Item = T;
+	type IntoIter = IntoIter<N,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		IntoIter
+		{
+			list:self
+		}
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a StaticLinkedList<N,T>
+{
+	type Item = &'a T;
+	type IntoIter = Iter<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a mut StaticLinkedList<N,T>
+{
+	type Item = &'a mut T;
+	type IntoIter = IterMut<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+/// The iterator of `StaticLinkedList`, created by `StaticLinkedList::iter` method.
+pub struct Iter<'a,T>
+{
+	nodes:&'a [Node<T>],
+	head:usize,
+	tail:usize,
+	remaining:usize
+}
+
+impl<'a,T> Clone for Iter<'a,T>
+{
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			nodes:self.nodes,
+			head:self.head,
+			tail:self.tail,
+			remaining:self.remaining
+		}
+	}
+}
+
+impl<'a,T> Iterator for Iter<'a,T>
+{
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.remaining==0
+		{
+			return None;
+		}
+		let &Node::Occupied{ref value,next,..}=&self.nodes[self.head] else {unreachable!()};
+		self.head=next;
+		self.remaining-=1;
+		Some(value)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,T> DoubleEndedIterator for Iter<'a,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		if self.remaining==0
+		{
+			return None;
+		}
+		let &Node::Occupied{ref value,prev,..}=&self.nodes[self.tail] else {unreachable!()};
+		self.tail=prev;
+		self.remaining-=1;
+		Some(value)
+	}
+}
+
+impl<'a,T> ExactSizeIterator for Iter<'a,T> {}
+impl<'a,T> FusedIterator for Iter<'a,T> {}
+
+/// The mutable iterator of `StaticLinkedList`, created by `StaticLinkedList::iter_mut` method.
+pub struct IterMut<'a,T>
+{
+	nodes:*mut Node<T>,
+	head:usize,
+	tail:usize,
+	remaining:usize,
+	phantom:PhantomData<&'a mut T>
+}
+
+impl<'a,T> Iterator for IterMut<'a,T>
+{
+	type Item = &'a mut T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.remaining==0
+		{
+			return None;
+		}
+		// Every node is yielded at most once, so the mutable references never alias.
+		let &mut Node::Occupied{ref mut value,next,..}=(unsafe {&mut *self.nodes.add(self.head)}) else {unreachable!()};
+		self.head=next;
+		self.remaining-=1;
+		Some(value)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,T> DoubleEndedIterator for IterMut<'a,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		if self.remaining==0
+		{
+			return None;
+		}
+		// Every node is yielded at most once, so the mutable references never alias.
+		let &mut Node::Occupied{ref mut value,prev,..}=(unsafe {&mut *self.nodes.add(self.tail)}) else {unreachable!()};
+		self.tail=prev;
+		self.remaining-=1;
+		Some(value)
+	}
+}
+
+impl<'a,T> ExactSizeIterator for IterMut<'a,T> {}
+impl<'a,T> FusedIterator for IterMut<'a,T> {}
+unsafe impl<'a,T:Send> Send for IterMut<'a,T> {}
+unsafe impl<'a,T:Sync> Sync for IterMut<'a,T> {}
+
+/// The by-value iterator of `StaticLinkedList`. \
+/// Values that are not yielded are dropped when the iterator is dropped.
+pub struct IntoIter<const N:usize,T>
+{
+	list:StaticLinkedList<N,T>
+}
+
+impl<const N:usize,T> Iterator for IntoIter<N,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.list.pop_front()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.list.len(),Some(self.list.len()))
+	}
+}
+
+impl<const N:usize,T> DoubleEndedIterator for IntoIter<N,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.list.pop_back()
+	}
+}
+
+impl<const N:usize,T> ExactSizeIterator for IntoIter<N,T> {}
+impl<const N:usize,T> FusedIterator for IntoIter<N,T> {}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::collections::VecDeque;
+	use crate::{linked_list::StaticLinkedList, xorshift};
+
+	#[test] fn cursor_ops()
+	{
+		let mut state:u64=0x2545_F491_4F6C_DD1D;
+		let mut l:StaticLinkedList<32,u64>=StaticLinkedList::new();
+		let mut model:VecDeque<u64>=VecDeque::new();
+		for _ in 0..20000
+		{
+			let r=xorshift(&mut state);
+			let pos=if model.is_empty() {0} else {(r>>16) as usize%model.len()};
+			let mut c=l.cursor_front_mut();
+			for _ in 0..pos
+			{
+				c.move_next();
+			}
+			match r%8
+			{
+				0=>match l.try_push_front(r)
+				{
+					Ok(())=>model.push_front(r),
+					Err(e)=>assert_eq!((e.into_inner(),model.len()),(r,32))
+				}
+				1=>match l.try_push_back(r)
+				{
+					Ok(())=>model.push_back(r),
+					Err(e)=>assert_eq!((e.into_inner(),model.len()),(r,32))
+				}
+				2=>assert_eq!(l.pop_front(),model.pop_front()),
+				3=>assert_eq!(l.pop_back(),model.pop_back()),
+				4=>if c.try_insert_after(r).is_ok()
+				{
+					model.insert(if model.is_empty() {0} else {pos+1},r);
+				}
+				5=>if c.try_insert_before(r).is_ok()
+				{
+					model.insert(pos,r);
+				}
+				6=>assert_eq!(c.remove_current(),model.remove(pos)),
+				_=>
+				{
+					c.move_to_front();
+					if let Some(v)=model.remove(pos)
+					{
+						model.push_front(v);
+					}
+				}
+			}
+			assert_eq!(l.len(),model.len());
+			assert!(l.iter().eq(model.iter()));
+			assert!(l.iter().rev().eq(model.iter().rev()));
+		}
+	}
+
+	#[test] fn splice_at_ghost()
+	{
+		let mut l:StaticLinkedList<8,u64>=StaticLinkedList::new();
+		// The cursor of an empty list points to the "ghost" non-element.
+		let mut c=l.cursor_front_mut();
+		assert_eq!(c.node(),None);
+		c.splice_after([3,4].into_iter().collect::<StaticLinkedList<2,u64>>());
+		c.splice_before([5,6].into_iter().collect::<StaticLinkedList<2,u64>>());
+		assert_eq!(c.node(),None);
+		assert!(l.iter().eq(&[3,4,5,6]));
+		// Move past the back value onto the ghost.
+		let mut c=l.cursor_back_mut();
+		c.move_next();
+		assert_eq!(c.current(),None);
+		c.splice_after([1,2].into_iter().collect::<StaticLinkedList<2,u64>>());
+		assert_eq!(c.peek_next(),Some(&mut 1));
+		// Splicing exactly up to the capacity succeeds, but one more value is rejected with `other` untouched.
+		let e=c.try_splice_before([9,9,9].into_iter().collect::<StaticLinkedList<4,u64>>()).unwrap_err().into_inner();
+		assert!(e.iter().eq(&[9,9,9]));
+		c.splice_before([7,8].into_iter().collect::<StaticLinkedList<2,u64>>());
+		assert_eq!(c.peek_prev(),Some(&mut 8));
+		assert!(l.is_full());
+		assert!(l.iter().eq(&[1,2,3,4,5,6,7,8]));
+		assert!(l.iter().rev().eq(&[8,7,6,5,4,3,2,1]));
+	}
+}
@@ -0,0 +1,370 @@
+// The static-LRU-cache module
+
+use core::{borrow::Borrow, fmt, hash::{BuildHasher, Hash}, iter::FusedIterator, mem};
+
+use crate::{hash_map::{FxBuildHasher, StaticHashMap}, linked_list::{self, StaticLinkedList}};
+
+/// The `StaticLruCache` type is a fixed-capacity cache which holds `N` entries at most. \
+/// When a new entry is put into a full cache, the least recently used entry is evicted. \
+/// The capacity is fixed, so the cache is never resized.
+/// 
+/// Entries are kept in a `StaticLinkedList` in order of use, and a `StaticHashMap` maps keys to the nodes of the list.
+/// Therefore, keys are stored twice and must implement `Clone`.
+/// 
+/// # Example
+/// ```
+/// use static_collections::lru::StaticLruCache;
+/// let mut c:StaticLruCache<2,u32,&str>=StaticLruCache::new();
+/// assert_eq!(c.put(1,"one"),None);
+/// assert_eq!(c.put(2,"two"),None);
+/// assert_eq!(c.get(&1),Some(&"one"));
+/// assert_eq!(c.put(3,"three"),Some((2,"two")));
+/// assert_eq!(c.peek(&2),None);
+/// ```
+pub struct StaticLruCache<const N:usize,K,V,S=FxBuildHasher>
+{
+	map:StaticHashMap<N,K,usize,S>,
+	// The front is the most recently used entry.
+	list:StaticLinkedList<N,(K,V)>
+}
+
+impl<const N:usize,K,V> StaticLruCache<N,K,V,FxBuildHasher>
+{
+	/// Constructs a new, empty `StaticLruCache` with the default `FxBuildHasher`.
+	pub const fn new()->Self
+	{
+		Self::with_hasher(FxBuildHasher)
+	}
+}
+
+impl<const N:usize,K,V,S:Default> Default for StaticLruCache<N,K,V,S>
+{
+	fn default() -> Self
+	{
+		Self::with_hasher(S::default())
+	}
+}
+
+impl<const N:usize,K,V,S> StaticLruCache<N,K,V,S>
+{
+	/// Constructs a new, empty `StaticLruCache` which will use the given hasher builder to hash keys.
+	pub const fn with_hasher(hasher:S)->Self
+	{
+		Self
+		{
+			map:StaticHashMap::with_hasher(hasher),
+			list:StaticLinkedList::new()
+		}
+	}
+
+	/// Returns the number of entries in the static-LRU-cache.
+	pub const fn len(&self)->usize
+	{
+		self.list.len()
+	}
+
+	/// Checks if the static-LRU-cache is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.list.is_empty()
+	}
+
+	/// Checks if the static-LRU-cache is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.list.is_full()
+	}
+
+	/// Returns the number of entries the static-LRU-cache can hold.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Returns a reference to the hasher builder of the static-LRU-cache.
+	pub const fn hasher(&self)->&S
+	{
+		self.map.hasher()
+	}
+
+	/// Returns an iterator of all entries, from the most recently used to the least recently used. \
+	/// Iterating does not update the order of use.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::lru::StaticLruCache;
+	/// let mut c:StaticLruCache<4,u32,u32>=StaticLruCache::new();
+	/// c.put(1,10);
+	/// c.put(2,20);
+	/// assert!(c.iter().eq([(&2,&20),(&1,&10)]));
+	/// ```
+	pub fn iter(&self)->Iter<'_,K,V>
+	{
+		Iter{inner:self.list.iter()}
+	}
+
+	/// Returns the least recently used entry without updating the order of use.
+	pub fn peek_lru(&self)->Option<(&K,&V)>
+	{
+		self.list.back().map(|(k,v)| (k,v))
+	}
+
+	/// Removes all entries from the static-LRU-cache.
+	pub fn clear(&mut self)
+	{
+		self.map.clear();
+		self.list.clear();
+	}
+}
+
+impl<const N:usize,K:Hash+Eq+Clone,V,S:BuildHasher> StaticLruCache<N,K,V,S>
+{
+	// Marks the entry in the node as the most recently used, and returns it.
+	fn promote(&mut self,node:usize)->&mut (K,V)
+	{
+		if let Some(mut c)=self.list.cursor_mut_at(node)
+		{
+			c.move_to_front();
+		}
+		self.list.front_mut().unwrap()
+	}
+
+	/// Returns a reference to the value of the key, and marks the entry as the most recently used.
+	pub fn get<Q:?Sized+Hash+Eq>(&mut self,key:&Q)->Option<&V> where K:Borrow<Q>
+	{
+		let node=*self.map.get(key)?;
+		Some(&self.promote(node).1)
+	}
+
+	/// Returns a mutable reference to the value of the key, and marks the entry as the most recently used.
+	pub fn get_mut<Q:?Sized+Hash+Eq>(&mut self,key:&Q)->Option<&mut V> where K:Borrow<Q>
+	{
+		let node=*self.map.get(key)?;
+		Some(&mut self.promote(node).1)
+	}
+
+	/// Returns a reference to the value of the key without updating the order of use.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::lru::StaticLruCache;
+	/// let mut c:StaticLruCache<2,u32,u32>=StaticLruCache::new();
+	/// c.put(1,10);
+	/// c.put(2,20);
+	/// assert_eq!(c.peek(&1),Some(&10));
+	/// assert_eq!(c.put(3,30),Some((1,10)));
+	/// ```
+	pub fn peek<Q:?Sized+Hash+Eq>(&self,key:&Q)->Option<&V> where K:Borrow<Q>
+	{
+		let node=*self.map.get(key)?;
+		self.list.value(node).map(|(_,v)| v)
+	}
+
+	/// Returns a mutable reference to the value of the key without updating the order of use.
+	pub fn peek_mut<Q:?Sized+Hash+Eq>(&mut self,key:&Q)->Option<&mut V> where K:Borrow<Q>
+	{
+		let node=*self.map.get(key)?;
+		self.list.value_mut(node).map(|(_,v)| v)
+	}
+
+	/// Checks if the static-LRU-cache contains the key without updating the order of use.
+	pub fn contains<Q:?Sized+Hash+Eq>(&self,key:&Q)->bool where K:Borrow<Q>
+	{
+		self.map.contains_key(key)
+	}
+
+	/// Puts a key-value pair into the static-LRU-cache, and marks the entry as the most recently used.
+	/// 
+	/// - If the key is already present, the value is replaced, and the key with the old value is returned.
+	/// - If the static-LRU-cache is full, the least recently used entry is evicted and returned.
+	/// - Otherwise, `None` is returned.
+	/// 
+	/// If the capacity is zero, the given pair is returned immediately.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::lru::StaticLruCache;
+	/// let mut c:StaticLruCache<1,u32,u32>=StaticLruCache::new();
+	/// assert_eq!(c.put(1,10),None);
+	/// assert_eq!(c.put(1,11),Some((1,10)));
+	/// assert_eq!(c.put(2,20),Some((1,11)));
+	/// ```
+	pub fn put(&mut self,key:K,value:V)->Option<(K,V)>
+	{
+		if let Some(&node)=self.map.get(&key)
+		{
+			let old=mem::replace(&mut self.promote(node).1,value);
+			return Some((key,old));
+		}
+		let evicted=if self.is_full() {self.pop_lru()} else {None};
+		match self.list.try_push_front((key.clone(),value))
+		{
+			Ok(())=>
+			{
+				let node=self.list.cursor_front_mut().node().unwrap();
+				if self.map.insert(key,node).is_err()
+				{
+					unreachable!("The map of StaticLruCache is out of sync!");
+				}
+				evicted
+			}
+			Err(e)=>Some(e.into_inner())
+		}
+	}
+
+	/// Removes the entry of the key, and returns the value.
+	pub fn remove<Q:?Sized+Hash+Eq>(&mut self,key:&Q)->Option<V> where K:Borrow<Q>
+	{
+		let node=self.map.remove(key)?;
+		self.list.cursor_mut_at(node).and_then(|mut c| c.remove_current()).map(|(_,v)| v)
+	}
+
+	/// Removes the least recently used entry, and returns it.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::lru::StaticLruCache;
+	/// let mut c:StaticLruCache<4,u32,u32>=StaticLruCache::new();
+	/// c.put(1,10);
+	/// c.put(2,20);
+	/// c.get(&1);
+	/// assert_eq!(c.pop_lru(),Some((2,20)));
+	/// assert_eq!(c.pop_lru(),Some((1,10)));
+	/// assert_eq!(c.pop_lru(),None);
+	/// ```
+	pub fn pop_lru(&mut self)->Option<(K,V)>
+	{
+		let (k,v)=self.list.pop_back()?;
+		self.map.remove(&k);
+		Some((k,v))
+	}
+}
+
+impl<const N:usize,K:Hash+Eq+Clone,V:Clone,S:BuildHasher+Clone> Clone for StaticLruCache<N,K,V,S>
+{
+	fn clone(&self) -> Self
+	{
+		// Node indices are not preserved by cloning the list, so entries are put again from the least recently used.
+		let mut c=Self::with_hasher(self.hasher().clone());
+		for (k,v) in self.iter().rev()
+		{
+			c.put(k.clone(),v.clone());
+		}
+		c
+	}
+}
+
+impl<const N:usize,K:fmt::Debug,V:fmt::Debug,S> fmt::Debug for StaticLruCache<N,K,V,S>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<'a,const N:usize,K,V,S> IntoIterator for &'a StaticLruCache<N,K,V,S>
+{
+	type Item = (&'a K,&'a V);
+	type IntoIter = Iter<'a,K,V>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+/// The iterator of `StaticLruCache`, created by `StaticLruCache::iter` method.
+pub struct Iter<'a,K,V>
+{
+	inner:linked_list::Iter<'a,(K,V)>
+}
+
+impl<'a,K,V> Iterator for Iter<'a,K,V>
+{
+	type Item = (&'a K,&'a V);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next().map(|(k,v)| (k,v))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+impl<'a,K,V> DoubleEndedIterator for Iter<'a,K,V>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.inner.next_back().map(|(k,v)| (k,v))
+	}
+}
+
+impl<'a,K,V> ExactSizeIterator for Iter<'a,K,V> {}
+impl<'a,K,V> FusedIterator for Iter<'a,K,V> {}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::vec::Vec;
+	use crate::{lru::StaticLruCache, xorshift};
+
+	#[test] fn eviction_order()
+	{
+		let mut state:u64=0x9E37_79B9_7F4A_7C15;
+		let mut c:StaticLruCache<16,u64,u64>=StaticLruCache::new();
+		// The model is ordered from the least recently used to the most recently used.
+		let mut model:Vec<(u64,u64)>=Vec::new();
+		for _ in 0..20000
+		{
+			let r=xorshift(&mut state);
+			let k=(r>>8)%32;
+			let pos=model.iter().position(|(mk,_)| *mk==k);
+			match r%4
+			{
+				0|1=>
+				{
+					let expected=match pos
+					{
+						Some(i)=>
+						{
+							let (mk,mv)=model.remove(i);
+							Some((mk,mv))
+						}
+						None if model.len()==16=>Some(model.remove(0)),
+						None=>None
+					};
+					assert_eq!(c.put(k,r),expected);
+					model.push((k,r));
+				}
+				2=>
+				{
+					assert_eq!(c.get(&k),pos.map(|i| &model[i].1));
+					if let Some(i)=pos
+					{
+						let e=model.remove(i);
+						model.push(e);
+					}
+				}
+				_=>
+				{
+					if r&0x100==0
+					{
+						assert_eq!(c.remove(&k),pos.map(|i| model.remove(i).1));
+					}
+					else
+					{
+						assert_eq!(c.pop_lru(),if model.is_empty() {None} else {Some(model.remove(0))});
+					}
+				}
+			}
+			assert_eq!(c.len(),model.len());
+			assert!(c.iter().rev().eq(model.iter().map(|(k,v)| (k,v))));
+		}
+		let d=c.clone();
+		assert!(d.iter().eq(c.iter()));
+	}
+}
@@ -0,0 +1,500 @@
+// The static-slab module
+
+use core::{fmt, iter::{Enumerate, FusedIterator}, mem, ops::{Index, IndexMut}, slice};
+
+use crate::vec::{self, CapacityError, StaticVec};
+
+/// The handle of a value stored in a `StaticSlab`. \
+/// The handle stays valid until the value is removed, regardless of other insertions and removals.
+/// 
+/// A removed slot may be reused by a later insertion. In this case, the old handle refers to the new value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Key(usize);
+
+impl Key
+{
+	/// Returns the slot index of this handle.
+	pub const fn index(self)->usize
+	{
+		self.0
+	}
+
+	/// Creates a handle from a slot index.
+	pub const fn from_index(index:usize)->Self
+	{
+		Self(index)
+	}
+}
+
+#[derive(Clone)]
+enum Slot<T>
+{
+	Occupied(T),
+	// A vacant slot holds the index of the next vacant slot.
+	Vacant(usize)
+}
+
+/// The `StaticSlab` type is a fixed-capacity object pool which can hold `N` values at most. \
+/// Values are referred by `Key` handles. Vacant slots are linked in an embedded free list,
+/// so both insertion and removal are `O(1)`.
+/// 
+/// # Example
+/// ```
+/// use static_collections::slab::StaticSlab;
+/// let mut s:StaticSlab<4,&str>=StaticSlab::new();
+/// let a=s.insert("a").unwrap();
+/// let b=s.insert("b").unwrap();
+/// assert_eq!(s.remove(a),Some("a"));
+/// assert_eq!(s[b],"b");
+/// assert_eq!(s.get(a),None);
+/// ```
+pub struct StaticSlab<const N:usize,T>
+{
+	slots:StaticVec<N,Slot<T>>,
+	// Head of the free list. If it equals to `slots.len()`, there is no vacant slot in the list.
+	next:usize,
+	length:usize
+}
+
+impl<const N:usize,T> Default for StaticSlab<N,T>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,T> StaticSlab<N,T>
+{
+	/// Constructs a new, empty `StaticSlab`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::slab::StaticSlab;
+	/// let s:StaticSlab<8,u64>=StaticSlab::new();
+	/// assert!(s.is_empty());
+	/// ```
+	pub const fn new()->Self
+	{
+		Self
+		{
+			slots:StaticVec::new(),
+			next:0,
+			length:0
+		}
+	}
+
+	/// Returns the number of values in the static-slab.
+	pub const fn len(&self)->usize
+	{
+		self.length
+	}
+
+	/// Checks if the static-slab is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.length==0
+	}
+
+	/// Checks if the static-slab is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.length==N
+	}
+
+	/// Returns the number of values the static-slab can hold.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Returns the key that the next insertion would return, or `None` if the static-slab is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::slab::StaticSlab;
+	/// let mut s:StaticSlab<8,u64>=StaticSlab::new();
+	/// let k=s.vacant_key().unwrap();
+	/// assert_eq!(s.insert(k.index() as u64),Some(k));
+	/// ```
+	pub fn vacant_key(&self)->Option<Key>
+	{
+		if self.next<N {Some(Key(self.next))} else {None}
+	}
+
+	/// Inserts a value into the static-slab, and returns the key of the value. \
+	/// Returns `None` if the static-slab is full. In this case, the value is dropped. \
+	/// Use `try_insert` method if you would like to take the value back.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::slab::StaticSlab;
+	/// let mut s:StaticSlab<1,u64>=StaticSlab::new();
+	/// assert!(s.insert(1).is_some());
+	/// assert_eq!(s.insert(2),None);
+	/// ```
+	pub fn insert(&mut self,value:T)->Option<Key>
+	{
+		self.try_insert(value).ok()
+	}
+
+	/// Inserts a value into the static-slab, and returns the key of the value. \
+	/// Returns `Err(CapacityError<T>)` with the value if the static-slab is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::slab::StaticSlab;
+	/// let mut s:StaticSlab<1,u64>=StaticSlab::new();
+	/// assert!(s.try_insert(1).is_ok());
+	/// assert_eq!(s.try_insert(2).unwrap_err().into_inner(),2);
+	/// ```
+	pub fn try_insert(&mut self,value:T)->Result<Key,CapacityError<T>>
+	{
+		let key=self.next;
+		if key==self.slots.len()
+		{
+			if let Err(e)=self.slots.try_push(Slot::Occupied(value))
+			{
+				let Slot::Occupied(v)=e.into_inner() else {unreachable!()};
+				return Err(CapacityError::new(v));
+			}
+			self.next+=1;
+		}
+		else
+		{
+			match mem::replace(&mut self.slots[key],Slot::Occupied(value))
+			{
+				Slot::Vacant(next)=>self.next=next,
+				Slot::Occupied(_)=>unreachable!("The free list of StaticSlab is corrupted!")
+			}
+		}
+		self.length+=1;
+		Ok(Key(key))
+	}
+
+	/// Removes the value of the `key` from the static-slab, and returns it. \
+	/// Returns `None` if the `key` does not refer to any value.
+	pub fn remove(&mut self,key:Key)->Option<T>
+	{
+		let slot=self.slots.get_mut(key.0)?;
+		if let Slot::Vacant(_)=slot
+		{
+			return None;
+		}
+		let Slot::Occupied(v)=mem::replace(slot,Slot::Vacant(self.next)) else {unreachable!()};
+		self.next=key.0;
+		self.length-=1;
+		Some(v)
+	}
+
+	/// Checks if the `key` refers to a value in the static-slab.
+	pub fn contains(&self,key:Key)->bool
+	{
+		self.get(key).is_some()
+	}
+
+	/// Returns a reference to the value of the `key`, or `None` if the `key` does not refer to any value.
+	pub fn get(&self,key:Key)->Option<&T>
+	{
+		match self.slots.get(key.0)
+		{
+			Some(Slot::Occupied(v))=>Some(v),
+			_=>None
+		}
+	}
+
+	/// Returns a mutable reference to the value of the `key`, or `None` if the `key` does not refer to any value.
+	pub fn get_mut(&mut self,key:Key)->Option<&mut T>
+	{
+		match self.slots.get_mut(key.0)
+		{
+			Some(Slot::Occupied(v))=>Some(v),
+			_=>None
+		}
+	}
+
+	/// Returns an iterator of the keys and values in the static-slab, in ascending order of keys.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::slab::StaticSlab;
+	/// let mut s:StaticSlab<8,char>=StaticSlab::new();
+	/// let a=s.insert('a').unwrap();
+	/// let b=s.insert('b').unwrap();
+	/// assert!(s.iter().eq([(a,&'a'),(b,&'b')]));
+	/// ```
+	pub fn iter(&self)->Iter<'_,T>
+	{
+		Iter
+		{
+			slots:self.slots.iter().enumerate(),
+			remaining:self.length
+		}
+	}
+
+	/// Returns an iterator of the keys and mutable references to values in the static-slab, in ascending order of keys.
+	pub fn iter_mut(&mut self)->IterMut<'_,T>
+	{
+		IterMut
+		{
+			slots:self.slots.iter_mut().enumerate(),
+			remaining:self.length
+		}
+	}
+
+	/// Retains only the values specified by the predicate. \
+	/// In other words, remove all values `v` with key `k` such that `f(k,&mut v)` returns `false`.
+	pub fn retain<F:FnMut(Key,&mut T)->bool>(&mut self,mut f:F)
+	{
+		for i in 0..self.slots.len()
+		{
+			if let Slot::Occupied(v)=&mut self.slots[i] && !f(Key(i),v)
+			{
+				self.remove(Key(i));
+			}
+		}
+	}
+
+	/// Removes all values from the static-slab and returns them as an iterator. \
+	/// All keys are invalidated, even if the iterator is not fully consumed.
+	pub fn drain(&mut self)->Drain<'_,N,T>
+	{
+		self.next=0;
+		let remaining=mem::replace(&mut self.length,0);
+		Drain
+		{
+			slots:self.slots.drain(..),
+			remaining
+		}
+	}
+
+	/// Removes all values from the static-slab. All keys are invalidated.
+	pub fn clear(&mut self)
+	{
+		self.slots.clear();
+		self.next=0;
+		self.length=0;
+	}
+}
+
+impl<const N:usize,T:Clone> Clone for StaticSlab<N,T>
+{
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			slots:self.slots.iter().cloned().collect(),
+			next:self.next,
+			length:self.length
+		}
+	}
+}
+
+impl<const N:usize,T:fmt::Debug> fmt::Debug for StaticSlab<N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<const N:usize,T> Index<Key> for StaticSlab<N,T>
+{
+	type Output = T;
+
+	fn index(&self, key: Key) -> &Self::Output
+	{
+		match self.get(key)
+		{
+			Some(v)=>v,
+			None=>panic!("Invalid StaticSlab key {}!",key.0)
+		}
+	}
+}
+
+impl<const N:usize,T> IndexMut<Key> for StaticSlab<N,T>
+{
+	fn index_mut(&mut self, key: Key) -> &mut Self::Output
+	{
+		match self.get_mut(key)
+		{
+			Some(v)=>v,
+			None=>panic!("Invalid StaticSlab key {}!",key.0)
+		}
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a StaticSlab<N,T>
+{
+	type Item = (Key,&'a T);
+	type IntoIter = Iter<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a mut StaticSlab<N,T>
+{
+	type Item = (Key,&'a mut T);
+	type IntoIter = IterMut<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+/// Immutable static-slab iterator.
+pub struct Iter<'a,T>
+{
+	slots:Enumerate<slice::Iter<'a,Slot<T>>>,
+	remaining:usize
+}
+
+impl<'a,T> Iterator for Iter<'a,T>
+{
+	type Item = (Key,&'a T);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let r=self.slots.find_map(|(i,s)| match s {Slot::Occupied(v)=>Some((Key(i),v)),Slot::Vacant(_)=>None})?;
+		self.remaining-=1;
+		Some(r)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,T> ExactSizeIterator for Iter<'a,T> {}
+impl<'a,T> FusedIterator for Iter<'a,T> {}
+
+/// Mutable static-slab iterator.
+pub struct IterMut<'a,T>
+{
+	slots:Enumerate<slice::IterMut<'a,Slot<T>>>,
+	remaining:usize
+}
+
+impl<'a,T> Iterator for IterMut<'a,T>
+{
+	type Item = (Key,&'a mut T);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let r=self.slots.find_map(|(i,s)| match s {Slot::Occupied(v)=>Some((Key(i),v)),Slot::Vacant(_)=>None})?;
+		self.remaining-=1;
+		Some(r)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,T> ExactSizeIterator for IterMut<'a,T> {}
+impl<'a,T> FusedIterator for IterMut<'a,T> {}
+
+/// A draining iterator of a static-slab. \
+/// This struct is created by the `drain` method of `StaticSlab`.
+pub struct Drain<'a,const N:usize,T>
+{
+	slots:vec::Drain<'a,N,Slot<T>>,
+	remaining:usize
+}
+
+impl<'a,const N:usize,T> Iterator for Drain<'a,N,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let r=self.slots.find_map(|s| match s {Slot::Occupied(v)=>Some(v),Slot::Vacant(_)=>None})?;
+		self.remaining-=1;
+		Some(r)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining,Some(self.remaining))
+	}
+}
+
+impl<'a,const N:usize,T> ExactSizeIterator for Drain<'a,N,T> {}
+impl<'a,const N:usize,T> FusedIterator for Drain<'a,N,T> {}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use std::vec::Vec;
+	use crate::{slab::{Key, StaticSlab}, xorshift};
+
+	#[test] fn free_list()
+	{
+		let mut state:u64=0x0123_4567_89AB_CDEF;
+		let mut s:StaticSlab<32,u64>=StaticSlab::new();
+		let mut model:Vec<(Key,u64)>=Vec::new();
+		for _ in 0..10000
+		{
+			let r=xorshift(&mut state);
+			if r.is_multiple_of(2)
+			{
+				match s.try_insert(r)
+				{
+					Ok(k)=>
+					{
+						assert!(model.iter().all(|(mk,_)| *mk!=k));
+						model.push((k,r));
+					}
+					Err(e)=>
+					{
+						assert_eq!(model.len(),32);
+						assert_eq!(e.into_inner(),r);
+					}
+				}
+			}
+			else if !model.is_empty()
+			{
+				let (k,v)=model.swap_remove((r>>8) as usize%model.len());
+				assert_eq!(s.remove(k),Some(v));
+				assert_eq!(s.remove(k),None);
+			}
+			assert_eq!(s.len(),model.len());
+		}
+		model.sort();
+		assert!(s.iter().eq(model.iter().map(|(k,v)| (*k,v))));
+	}
+
+	#[test] fn stale_key_reuse()
+	{
+		let mut s:StaticSlab<3,&str>=StaticSlab::new();
+		let a=s.insert("a").unwrap();
+		let b=s.insert("b").unwrap();
+		let c=s.insert("c").unwrap();
+		assert!(s.is_full());
+		assert_eq!(s.vacant_key(),None);
+		assert_eq!(s.try_insert("d").unwrap_err().into_inner(),"d");
+		// Vacant slots are reused in the reverse order of removal.
+		assert_eq!(s.remove(a),Some("a"));
+		assert_eq!(s.remove(c),Some("c"));
+		assert_eq!(s.remove(c),None);
+		assert_eq!(s.vacant_key(),Some(c));
+		assert_eq!(s.insert("e"),Some(c));
+		assert_eq!(s.insert("f"),Some(a));
+		// The stale handles refer to the new values.
+		assert_eq!(s[a],"f");
+		assert_eq!(s[c],"e");
+		assert_eq!(s[b],"b");
+		// A handle beyond the slots is never valid.
+		let k=Key::from_index(3);
+		assert!(!s.contains(k));
+		assert_eq!(s.get(k),None);
+		assert_eq!(s.remove(k),None);
+	}
+}
@@ -0,0 +1,523 @@
+// The slice-vector module
+
+use core::{fmt, iter::FusedIterator, mem::{self, MaybeUninit}, ops::{Deref, DerefMut, RangeBounds}, ptr, slice};
+
+use crate::vec::{resolve_range, CapacityError};
+
+/// The `SliceVec` type is a growable array over a borrowed buffer of uninitialized memory. \
+/// Unlike `StaticVec`, the capacity is the length of the buffer, which is only known at runtime.
+/// 
+/// A `SliceVec` created by `SliceVec::new` owns its values, so they are dropped when the `SliceVec` is dropped. \
+/// A `SliceVec` created by `StaticVec::as_slice_vec` is a view of the static vector,
+/// so its values are handed back to the static vector when the view is dropped.
+/// 
+/// # Example
+/// ```
+/// use core::mem::MaybeUninit;
+/// use static_collections::slice_vec::SliceVec;
+/// let mut buff=[const {MaybeUninit::uninit()};3];
+/// let mut v:SliceVec<u64>=SliceVec::new(&mut buff);
+/// v.push(1);
+/// v.push(3);
+/// v.insert(1,2);
+/// assert_eq!(v.as_slice(),&[1,2,3]);
+/// assert_eq!(v.try_push(4).unwrap_err().into_inner(),4);
+/// ```
+pub struct SliceVec<'a,T>
+{
+	length:usize,
+	buff:&'a mut [MaybeUninit<T>],
+	// If set, the length is written back to it when the view is dropped, instead of dropping values.
+	sink:Option<&'a mut usize>
+}
+
+impl<'a,T> SliceVec<'a,T>
+{
+	/// Constructs a new, empty `SliceVec` over the buffer `buff`. \
+	/// The capacity of the slice-vector is the length of `buff`.
+	pub const fn new(buff:&'a mut [MaybeUninit<T>])->Self
+	{
+		Self
+		{
+			length:0,
+			buff,
+			sink:None
+		}
+	}
+
+	/// Constructs a view over the buffer `buff` whose first `*length` values are initialized. \
+	/// The length is reset to zero until the view is dropped, so leaking the view would only leak values.
+	/// 
+	/// # Safety
+	/// The first `*length` items of `buff` must be initialized, and `*length` must not exceed the length of `buff`.
+	pub(crate) unsafe fn from_raw_parts(buff:&'a mut [MaybeUninit<T>],length:&'a mut usize)->Self
+	{
+		Self
+		{
+			length:mem::replace(length,0),
+			buff,
+			sink:Some(length)
+		}
+	}
+
+	pub const fn as_slice(&self)->&[T]
+	{
+		unsafe
+		{
+			slice::from_raw_parts(self.as_ptr(),self.length)
+		}
+	}
+
+	pub const fn as_mut_slice(&mut self)->&mut [T]
+	{
+		unsafe
+		{
+			slice::from_raw_parts_mut(self.as_mut_ptr(),self.length)
+		}
+	}
+
+	pub const fn as_ptr(&self)->*const T
+	{
+		self.buff.as_ptr() as *const T
+	}
+
+	pub const fn as_mut_ptr(&mut self)->*mut T
+	{
+		self.buff.as_mut_ptr() as *mut T
+	}
+
+	/// Returns the number of values in the slice-vector.
+	pub const fn len(&self)->usize
+	{
+		self.length
+	}
+
+	/// Checks if the slice-vector is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.length==0
+	}
+
+	/// Checks if the slice-vector is full.
+	pub const fn is_full(&self)->bool
+	{
+		self.length==self.buff.len()
+	}
+
+	/// Returns the capacity of the slice-vector, which is the length of the buffer.
+	pub const fn capacity(&self)->usize
+	{
+		self.buff.len()
+	}
+
+	/// Put value `v` to the end of the slice-vector.
+	/// 
+	/// # Panic
+	/// If the slice-vector is full, this method would panic. \
+	/// Use `try_push` method if you would like to handle the overflow.
+	pub fn push(&mut self,v:T)
+	{
+		if self.try_push(v).is_err()
+		{
+			panic!("SliceVec buffer overflow!");
+		}
+	}
+
+	/// Put value `v` to the end of the slice-vector. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the slice-vector is full.
+	pub fn try_push(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		if self.length<self.buff.len()
+		{
+			self.buff[self.length].write(v);
+			self.length+=1;
+			Ok(())
+		}
+		else
+		{
+			Err(CapacityError::new(v))
+		}
+	}
+
+	/// Read and remove the value at the end of the slice-vector.
+	pub fn pop(&mut self)->Option<T>
+	{
+		if self.length>0
+		{
+			self.length-=1;
+			// Use unsafe codes to avoid `Copy` trait.
+			unsafe
+			{
+				Some(self.buff[self.length].assume_init_read())
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Insert value `v` to a specific location of the slice-vector.
+	/// 
+	/// # Panic
+	/// If the slice-vector is full, or if `index>len`, this method would panic. \
+	/// Use `try_insert` method if you would like to handle the overflow.
+	pub fn insert(&mut self,index:usize,v:T)
+	{
+		if self.try_insert(index,v).is_err()
+		{
+			panic!("SliceVec buffer overflow!");
+		}
+	}
+
+	/// Insert value `v` to a specific location of the slice-vector. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the slice-vector is full.
+	/// 
+	/// # Panic
+	/// If `index>len`, this method would panic.
+	pub fn try_insert(&mut self,index:usize,v:T)->Result<(),CapacityError<T>>
+	{
+		if index>self.length
+		{
+			panic!("Insertion index {index} is out of length {}!",self.length);
+		}
+		if self.length<self.buff.len()
+		{
+			// Use unsafe codes to avoid `Copy` trait.
+			unsafe
+			{
+				let p=self.as_mut_ptr().add(index);
+				ptr::copy(p,p.add(1),self.length-index);
+				ptr::write(p,v);
+			}
+			self.length+=1;
+			Ok(())
+		}
+		else
+		{
+			Err(CapacityError::new(v))
+		}
+	}
+
+	/// Read and remove the value at location `index` of the slice-vector.
+	pub fn remove(&mut self,index:usize)->Option<T>
+	{
+		if self.length>index
+		{
+			// Use unsafe codes to avoid `Copy` and `Drop` trait.
+			unsafe
+			{
+				let p=self.as_mut_ptr().add(index);
+				let v=ptr::read(p);
+				ptr::copy(p.add(1),p,self.length-index-1);
+				self.length-=1;
+				Some(v)
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Shortens the slice-vector, keeping the first `new_len` values and dropping the rest.
+	pub fn truncate(&mut self,new_len:usize)
+	{
+		if new_len<self.length
+		{
+			let tail:*mut [T]=&mut self.as_mut_slice()[new_len..];
+			// Set the length first in case dropping a value panics.
+			self.length=new_len;
+			unsafe
+			{
+				ptr::drop_in_place(tail);
+			}
+		}
+	}
+
+	/// Removes all values from the slice-vector.
+	pub fn clear(&mut self)
+	{
+		self.truncate(0);
+	}
+
+	/// Removes the specified range from the slice-vector, and returns the removed values as an iterator. \
+	/// If the iterator is dropped before being fully consumed, the remaining removed values are dropped.
+	/// 
+	/// # Panic
+	/// If the starting point is greater than the end point, or if the end point is greater than the length, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use core::mem::MaybeUninit;
+	/// use static_collections::slice_vec::SliceVec;
+	/// let mut buff=[const {MaybeUninit::uninit()};8];
+	/// let mut v:SliceVec<u64>=SliceVec::new(&mut buff);
+	/// v.extend([1,2,3,4,5]);
+	/// assert!(v.drain(1..3).eq([2,3]));
+	/// assert_eq!(v.as_slice(),&[1,4,5]);
+	/// ```
+	pub fn drain<R:RangeBounds<usize>>(&mut self,range:R)->Drain<'_,'a,T>
+	{
+		let old_len=self.length;
+		let r=resolve_range(range,old_len);
+		// Shorten the slice-vector first, so leaking the `Drain` would not expose moved values.
+		self.length=r.start;
+		Drain
+		{
+			vec:self,
+			index:r.start,
+			end:r.end,
+			tail_start:r.end,
+			tail_len:old_len-r.end
+		}
+	}
+}
+
+impl<'a,T> Drop for SliceVec<'a,T>
+{
+	fn drop(&mut self)
+	{
+		match self.sink.take()
+		{
+			// Hand the values back to the owner of the buffer.
+			Some(sink)=>*sink=self.length,
+			None=>self.clear()
+		}
+	}
+}
+
+impl<'a,T> Deref for SliceVec<'a,T>
+{
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target
+	{
+		self.as_slice()
+	}
+}
+
+impl<'a,T> DerefMut for SliceVec<'a,T>
+{
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		self.as_mut_slice()
+	}
+}
+
+impl<'a,T:fmt::Debug> fmt::Debug for SliceVec<'a,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<'a,T> Extend<T> for SliceVec<'a,T>
+{
+	/// Appends all values yielded by `iter` to the end of the slice-vector.
+	/// 
+	/// # Panic
+	/// If the slice-vector cannot hold all values, this method would panic.
+	fn extend<I:IntoIterator<Item=T>>(&mut self,iter:I)
+	{
+		for item in iter
+		{
+			self.push(item);
+		}
+	}
+}
+
+impl<'a,'b,T> IntoIterator for &'b SliceVec<'a,T>
+{
+	type Item = &'b T;
+	type IntoIter = slice::Iter<'b,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a,'b,T> IntoIterator for &'b mut SliceVec<'a,T>
+{
+	type Item = &'b mut T;
+	type IntoIter = slice::IterMut<'b,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+/// The draining iterator of `SliceVec`, created by `SliceVec::drain` method. \
+/// Values that are not yielded are dropped when the iterator is dropped.
+pub struct Drain<'b,'a,T>
+{
+	vec:&'b mut SliceVec<'a,T>,
+	index:usize,
+	end:usize,
+	tail_start:usize,
+	tail_len:usize
+}
+
+impl<'b,'a,T> Drain<'b,'a,T>
+{
+	/// Returns the remaining values of this iterator as a slice.
+	pub fn as_slice(&self)->&[T]
+	{
+		unsafe
+		{
+			slice::from_raw_parts(self.vec.as_ptr().add(self.index),self.end-self.index)
+		}
+	}
+}
+
+impl<'b,'a,T> Iterator for Drain<'b,'a,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.index<self.end
+		{
+			let v=unsafe{ptr::read(self.vec.as_ptr().add(self.index))};
+			self.index+=1;
+			Some(v)
+		}
+		else
+		{
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		let l=self.end-self.index;
+		(l,Some(l))
+	}
+}
+
+impl<'b,'a,T> DoubleEndedIterator for Drain<'b,'a,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		if self.index<self.end
+		{
+			self.end-=1;
+			unsafe
+			{
+				Some(ptr::read(self.vec.as_ptr().add(self.end)))
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+}
+
+impl<'b,'a,T> ExactSizeIterator for Drain<'b,'a,T> {}
+impl<'b,'a,T> FusedIterator for Drain<'b,'a,T> {}
+
+impl<'b,'a,T> Drop for Drain<'b,'a,T>
+{
+	fn drop(&mut self)
+	{
+		// Move the tail back even if dropping the remaining values panics.
+		struct TailGuard<'c,'b,'a,T>(&'c mut Drain<'b,'a,T>);
+
+		impl<'c,'b,'a,T> Drop for TailGuard<'c,'b,'a,T>
+		{
+			fn drop(&mut self)
+			{
+				let d=&mut *self.0;
+				let start=d.vec.length;
+				unsafe
+				{
+					let p=d.vec.as_mut_ptr();
+					ptr::copy(p.add(d.tail_start),p.add(start),d.tail_len);
+				}
+				d.vec.length=start+d.tail_len;
+			}
+		}
+
+		let g=TailGuard(self);
+		let remaining:*mut [T]=ptr::slice_from_raw_parts_mut(unsafe{g.0.vec.as_mut_ptr().add(g.0.index)},g.0.end-g.0.index);
+		g.0.index=g.0.end;
+		// Force drop every remaining item.
+		unsafe
+		{
+			ptr::drop_in_place(remaining);
+		}
+	}
+}
+
+impl<'b,'a,T:fmt::Debug> fmt::Debug for Drain<'b,'a,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_tuple("Drain").field(&self.as_slice()).finish()
+	}
+}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use core::mem::MaybeUninit;
+	use std::{rc::Rc, vec::Vec};
+	use crate::{slice_vec::SliceVec, vec::StaticVec, xorshift};
+
+	#[test] fn against_vec()
+	{
+		let mut state:u64=0x1234_5678_9ABC_DEF1;
+		let counter=Rc::new(());
+		let mut buff=[const {MaybeUninit::uninit()};20];
+		let mut v:SliceVec<(u64,Rc<()>)>=SliceVec::new(&mut buff);
+		let mut model:Vec<u64>=Vec::new();
+		for _ in 0..10000
+		{
+			let r=xorshift(&mut state);
+			let pos=(r>>16) as usize%(model.len()+1);
+			match r%6
+			{
+				0|1=>if v.try_push((r,counter.clone())).is_ok()
+				{
+					model.push(r);
+				}
+				2=>if v.try_insert(pos,(r,counter.clone())).is_ok()
+				{
+					model.insert(pos,r);
+				}
+				3=>assert_eq!(v.pop().map(|(x,_)| x),model.pop()),
+				4=>assert_eq!(v.remove(pos).map(|(x,_)| x),if pos<model.len() {Some(model.remove(pos))} else {None}),
+				_=>
+				{
+					let end=pos+(r>>40) as usize%(model.len()-pos+1);
+					let mut d=v.drain(pos..end);
+					// Leave some values for the destructor of `Drain`.
+					d.next();
+					drop(d);
+					model.drain(pos..end);
+				}
+			}
+			assert!(v.iter().map(|(x,_)| x).eq(model.iter()));
+			assert_eq!(Rc::strong_count(&counter),model.len()+1);
+		}
+		drop(v);
+		assert_eq!(Rc::strong_count(&counter),1);
+		// The view hands values back to the static vector.
+		let mut s:StaticVec<8,Rc<()>>=StaticVec::new();
+		s.push(counter.clone());
+		let mut w=s.as_slice_vec();
+		w.push(counter.clone());
+		w.push(counter.clone());
+		drop(w.remove(0));
+		drop(w);
+		assert_eq!(s.len(),2);
+		assert_eq!(Rc::strong_count(&counter),3);
+		core::mem::forget(s.as_slice_vec());
+		assert!(s.is_empty());
+	}
+}
@@ -0,0 +1,508 @@
+// The static-small-vector module
+
+use core::{fmt, mem::MaybeUninit, ops::{Deref, DerefMut}, ptr, slice};
+
+use crate::vec::CapacityError;
+
+/// The overflow region of a `StaticSmallVec`, which is borrowed from the caller.
+pub type Overflow<'a,T>=&'a mut [MaybeUninit<T>];
+
+/// The `StaticSmallVec` type is a sibling of `StaticVec` which holds `N` values inline. \
+/// An external overflow region can be given at runtime. When the inline buffer is full,
+/// all values are migrated to the overflow region, so the static-small-vector can hold
+/// as many values as the overflow region can hold. No heap allocation is involved.
+/// 
+/// Like `StaticVec`, it dereferences to `[T]`, so calling code does not change whether it has spilled or not.
+/// 
+/// # Example
+/// ```
+/// use core::mem::MaybeUninit;
+/// use static_collections::small_vec::StaticSmallVec;
+/// let mut region=[const {MaybeUninit::uninit()};8];
+/// let mut v:StaticSmallVec<2,u64>=StaticSmallVec::new();
+/// v.push(1);
+/// v.push(2);
+/// assert_eq!(v.try_push(3).unwrap_err().into_inner(),3);
+/// v.set_overflow(&mut region).unwrap();
+/// v.push(3);
+/// assert!(v.spilled());
+/// assert_eq!(v.as_slice(),&[1,2,3]);
+/// assert_eq!(v.capacity(),8);
+/// ```
+pub struct StaticSmallVec<'a,const N:usize,T>
+{
+	length:usize,
+	inline:MaybeUninit<[T;N]>,
+	overflow:Option<Overflow<'a,T>>,
+	// If set, the values are stored in the overflow region.
+	spilled:bool
+}
+
+impl<'a,const N:usize,T> Default for StaticSmallVec<'a,N,T>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<'a,const N:usize,T> StaticSmallVec<'a,N,T>
+{
+	/// Constructs a new, empty `StaticSmallVec` without an overflow region.
+	pub const fn new()->Self
+	{
+		Self
+		{
+			length:0,
+			inline:MaybeUninit::uninit(),
+			overflow:None,
+			spilled:false
+		}
+	}
+
+	/// Constructs a new, empty `StaticSmallVec` with the given overflow region.
+	pub const fn with_overflow(overflow:Overflow<'a,T>)->Self
+	{
+		Self
+		{
+			length:0,
+			inline:MaybeUninit::uninit(),
+			overflow:Some(overflow),
+			spilled:false
+		}
+	}
+
+	/// Sets the overflow region of the static-small-vector, and returns the previous one. \
+	/// If the static-small-vector has spilled, the values are migrated to the new overflow region,
+	/// or back to the inline buffer if the new overflow region is not larger than it. \
+	/// Returns `Err(CapacityError)` with the new overflow region if it cannot hold the values.
+	/// In this case, the static-small-vector is not modified.
+	/// 
+	/// # Example
+	/// ```
+	/// use core::mem::MaybeUninit;
+	/// use static_collections::small_vec::StaticSmallVec;
+	/// let mut small=[const {MaybeUninit::uninit()};2];
+	/// let mut large=[const {MaybeUninit::uninit()};4];
+	/// let mut v:StaticSmallVec<1,u64>=StaticSmallVec::with_overflow(&mut large);
+	/// v.extend([1,2,3]);
+	/// assert_eq!(v.set_overflow(&mut small).unwrap_err().into_inner().len(),2);
+	/// assert_eq!(v.as_slice(),&[1,2,3]);
+	/// ```
+	pub fn set_overflow(&mut self,overflow:Overflow<'a,T>)->Result<Option<Overflow<'a,T>>,CapacityError<Overflow<'a,T>>>
+	{
+		if self.spilled
+		{
+			if overflow.len()<self.length
+			{
+				return Err(CapacityError::new(overflow));
+			}
+			if overflow.len()>N
+			{
+				unsafe
+				{
+					ptr::copy_nonoverlapping(self.as_ptr(),overflow.as_mut_ptr() as *mut T,self.length);
+				}
+			}
+			else
+			{
+				// The new overflow region is not larger than the inline buffer, so values move back to it.
+				unsafe
+				{
+					ptr::copy_nonoverlapping(self.as_ptr(),self.inline.as_mut_ptr() as *mut T,self.length);
+				}
+				self.spilled=false;
+			}
+		}
+		Ok(self.overflow.replace(overflow))
+	}
+
+	/// Checks if the values have been migrated to the overflow region.
+	pub const fn spilled(&self)->bool
+	{
+		self.spilled
+	}
+
+	/// Returns the number of values the inline buffer can hold.
+	pub const fn inline_capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Returns the number of values the static-small-vector can hold,
+	/// which is the larger one of the inline capacity and the length of the overflow region.
+	pub const fn capacity(&self)->usize
+	{
+		match &self.overflow
+		{
+			Some(o) if o.len()>N=>o.len(),
+			_=>N
+		}
+	}
+
+	/// Returns the number of values in the static-small-vector.
+	pub const fn len(&self)->usize
+	{
+		self.length
+	}
+
+	/// Checks if the static-small-vector is empty.
+	pub const fn is_empty(&self)->bool
+	{
+		self.length==0
+	}
+
+	/// Checks if the static-small-vector is full, even if the values were migrated to the overflow region.
+	pub const fn is_full(&self)->bool
+	{
+		self.length==self.capacity()
+	}
+
+	pub const fn as_ptr(&self)->*const T
+	{
+		match &self.overflow
+		{
+			Some(o) if self.spilled=>o.as_ptr() as *const T,
+			_=>self.inline.as_ptr() as *const T
+		}
+	}
+
+	pub const fn as_mut_ptr(&mut self)->*mut T
+	{
+		match &mut self.overflow
+		{
+			Some(o) if self.spilled=>o.as_mut_ptr() as *mut T,
+			_=>self.inline.as_mut_ptr() as *mut T
+		}
+	}
+
+	pub const fn as_slice(&self)->&[T]
+	{
+		unsafe
+		{
+			slice::from_raw_parts(self.as_ptr(),self.length)
+		}
+	}
+
+	pub const fn as_mut_slice(&mut self)->&mut [T]
+	{
+		unsafe
+		{
+			slice::from_raw_parts_mut(self.as_mut_ptr(),self.length)
+		}
+	}
+
+	// Makes room for one more value, spilling to the overflow region if necessary.
+	fn reserve_one(&mut self)->bool
+	{
+		if self.spilled || self.length<N
+		{
+			return self.length<self.capacity();
+		}
+		match &mut self.overflow
+		{
+			Some(o) if o.len()>self.length=>
+			{
+				unsafe
+				{
+					ptr::copy_nonoverlapping(self.inline.as_ptr() as *const T,o.as_mut_ptr() as *mut T,self.length);
+				}
+				self.spilled=true;
+				true
+			}
+			_=>false
+		}
+	}
+
+	/// Put value `v` to the end of the static-small-vector.
+	/// 
+	/// # Panic
+	/// If the static-small-vector is full, this method would panic. \
+	/// Use `try_push` method if you would like to handle the overflow.
+	pub fn push(&mut self,v:T)
+	{
+		if self.try_push(v).is_err()
+		{
+			panic!("StaticSmallVec buffer overflow!");
+		}
+	}
+
+	/// Put value `v` to the end of the static-small-vector, spilling to the overflow region if necessary. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static-small-vector is full.
+	pub fn try_push(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		if self.reserve_one()
+		{
+			unsafe
+			{
+				// Use ptr::write to avoid `Drop` trait requirement.
+				ptr::write(self.as_mut_ptr().add(self.length),v);
+			}
+			self.length+=1;
+			Ok(())
+		}
+		else
+		{
+			Err(CapacityError::new(v))
+		}
+	}
+
+	/// Read and remove the value at the end of the static-small-vector.
+	pub fn pop(&mut self)->Option<T>
+	{
+		if self.length>0
+		{
+			self.length-=1;
+			// Use unsafe codes to avoid `Copy` trait.
+			unsafe
+			{
+				Some(ptr::read(self.as_ptr().add(self.length)))
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Insert value `v` to a specific location of the static-small-vector.
+	/// 
+	/// # Panic
+	/// If the static-small-vector is full, or if `index>len`, this method would panic. \
+	/// Use `try_insert` method if you would like to handle the overflow.
+	pub fn insert(&mut self,index:usize,v:T)
+	{
+		if self.try_insert(index,v).is_err()
+		{
+			panic!("StaticSmallVec buffer overflow!");
+		}
+	}
+
+	/// Insert value `v` to a specific location of the static-small-vector, spilling to the overflow region if necessary. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static-small-vector is full.
+	/// 
+	/// # Panic
+	/// If `index>len`, this method would panic.
+	pub fn try_insert(&mut self,index:usize,v:T)->Result<(),CapacityError<T>>
+	{
+		if index>self.length
+		{
+			panic!("Insertion index {index} is out of length {}!",self.length);
+		}
+		if self.reserve_one()
+		{
+			// Use unsafe codes to avoid `Copy` trait.
+			unsafe
+			{
+				let p=self.as_mut_ptr().add(index);
+				ptr::copy(p,p.add(1),self.length-index);
+				ptr::write(p,v);
+			}
+			self.length+=1;
+			Ok(())
+		}
+		else
+		{
+			Err(CapacityError::new(v))
+		}
+	}
+
+	/// Read and remove the value at location `index` of the static-small-vector.
+	pub fn remove(&mut self,index:usize)->Option<T>
+	{
+		if self.length>index
+		{
+			// Use unsafe codes to avoid `Copy` and `Drop` trait.
+			unsafe
+			{
+				let p=self.as_mut_ptr().add(index);
+				let v=ptr::read(p);
+				ptr::copy(p.add(1),p,self.length-index-1);
+				self.length-=1;
+				Some(v)
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Shortens the static-small-vector, keeping the first `new_len` values and dropping the rest. \
+	/// The values stay in the overflow region if the static-small-vector has spilled.
+	pub fn truncate(&mut self,new_len:usize)
+	{
+		if new_len<self.length
+		{
+			let tail:*mut [T]=&mut self.as_mut_slice()[new_len..];
+			// Set the length first in case dropping a value panics.
+			self.length=new_len;
+			unsafe
+			{
+				ptr::drop_in_place(tail);
+			}
+		}
+	}
+
+	/// Removes all values from the static-small-vector.
+	pub fn clear(&mut self)
+	{
+		self.truncate(0);
+	}
+}
+
+impl<'a,const N:usize,T> Drop for StaticSmallVec<'a,N,T>
+{
+	fn drop(&mut self)
+	{
+		self.clear();
+	}
+}
+
+impl<'a,const N:usize,T> Deref for StaticSmallVec<'a,N,T>
+{
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target
+	{
+		self.as_slice()
+	}
+}
+
+impl<'a,const N:usize,T> DerefMut for StaticSmallVec<'a,N,T>
+{
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		self.as_mut_slice()
+	}
+}
+
+impl<'a,const N:usize,T:fmt::Debug> fmt::Debug for StaticSmallVec<'a,N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<'a,const N:usize,T> Extend<T> for StaticSmallVec<'a,N,T>
+{
+	/// Appends all values yielded by `iter` to the end of the static-small-vector.
+	/// 
+	/// # Panic
+	/// If the static-small-vector cannot hold all values, this method would panic.
+	fn extend<I:IntoIterator<Item=T>>(&mut self,iter:I)
+	{
+		for item in iter
+		{
+			self.push(item);
+		}
+	}
+}
+
+impl<'a,'b,const N:usize,T> IntoIterator for &'b StaticSmallVec<'a,N,T>
+{
+	type Item = &'b T;
+	type IntoIter = slice::Iter<'b,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a,'b,const N:usize,T> IntoIterator for &'b mut StaticSmallVec<'a,N,T>
+{
+	type Item = &'b mut T;
+	type IntoIter = slice::IterMut<'b,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use core::mem::MaybeUninit;
+	use std::{rc::Rc, vec::Vec};
+	use crate::{small_vec::StaticSmallVec, xorshift};
+
+	#[test] fn spill()
+	{
+		let mut state:u64=0xDEAD_BEEF_CAFE_F00D;
+		let counter=Rc::new(());
+		let mut first=[const {MaybeUninit::uninit()};12];
+		let mut second=[const {MaybeUninit::uninit()};24];
+		let mut third=[const {MaybeUninit::uninit()};3];
+		let mut v:StaticSmallVec<4,(u64,Rc<()>)>=StaticSmallVec::new();
+		let mut model:Vec<u64>=Vec::new();
+		let mut regions=[&mut first[..],&mut second[..]].into_iter();
+		for _ in 0..10000
+		{
+			let r=xorshift(&mut state);
+			let pos=(r>>16) as usize%(model.len()+1);
+			match r%6
+			{
+				0|1=>match v.try_push((r,counter.clone()))
+				{
+					Ok(())=>model.push(r),
+					Err(_)=>
+					{
+						assert_eq!(model.len(),v.capacity());
+						if let Some(r)=regions.next()
+						{
+							assert!(v.set_overflow(r).is_ok());
+						}
+					}
+				}
+				2=>if v.try_insert(pos,(r,counter.clone())).is_ok()
+				{
+					model.insert(pos,r);
+				}
+				3=>assert_eq!(v.pop().map(|(x,_)| x),model.pop()),
+				4=>assert_eq!(v.remove(pos).map(|(x,_)| x),if pos<model.len() {Some(model.remove(pos))} else {None}),
+				_=>if r&0x300==0
+				{
+					v.truncate(pos);
+					model.truncate(pos);
+				}
+			}
+			assert!(v.iter().map(|(x,_)| x).eq(model.iter()));
+			assert_eq!(Rc::strong_count(&counter),model.len()+1);
+		}
+		assert!(v.spilled());
+		v.truncate(3);
+		assert!(v.set_overflow(&mut third).is_ok());
+		assert!(!v.spilled());
+		assert_eq!(v.len(),3);
+		assert_eq!(v.capacity(),4);
+		drop(v);
+		assert_eq!(Rc::strong_count(&counter),1);
+	}
+
+	#[test] fn spill_at_capacity()
+	{
+		let mut region=[const {MaybeUninit::uninit()};6];
+		let mut v:StaticSmallVec<3,u64>=StaticSmallVec::with_overflow(&mut region);
+		// Filling the inline buffer up to exactly N does not spill.
+		v.extend([1,2,3]);
+		assert!(!v.spilled());
+		assert_eq!(v.len(),v.inline_capacity());
+		// The (N+1)-th value migrates all values to the overflow region.
+		v.push(4);
+		assert!(v.spilled());
+		assert_eq!(v.as_slice(),&[1,2,3,4]);
+		v.insert(0,0);
+		v.push(5);
+		assert!(v.is_full());
+		assert_eq!(v.try_insert(1,9).unwrap_err().into_inner(),9);
+		assert_eq!(v.as_slice(),&[0,1,2,3,4,5]);
+		// Shrinking back to N values does not move them back to the inline buffer.
+		v.truncate(3);
+		assert!(v.spilled());
+		assert_eq!(v.as_slice(),&[0,1,2]);
+	}
+}
@@ -0,0 +1,514 @@
+// The single-producer single-consumer queue module
+
+use core::{cell::{Cell, UnsafeCell}, fmt, marker::PhantomData, mem::MaybeUninit, ptr, sync::atomic::{AtomicBool, AtomicUsize, Ordering}};
+
+use crate::vec::CapacityError;
+
+/// The `Queue` type is a fixed-capacity lock-free single-producer single-consumer queue. \
+/// The queue can be placed in a `static` and split into a `Producer` and a `Consumer`,
+/// which can be moved to different execution contexts (e.g.: an interrupt handler and a worker thread).
+/// 
+/// Only `core::sync::atomic` operations are used, so the queue does not require any locks or dynamic allocations.
+/// 
+/// # Example
+/// ```
+/// use static_collections::spsc::Queue;
+/// static Q:Queue<16,u32>=Queue::new();
+/// let (mut p,mut c)=Q.try_split().unwrap();
+/// p.enqueue(1).unwrap();
+/// p.enqueue(2).unwrap();
+/// assert_eq!(c.dequeue(),Some(1));
+/// assert_eq!(c.dequeue(),Some(2));
+/// assert_eq!(c.dequeue(),None);
+/// ```
+pub struct Queue<const N:usize,T>
+{
+	// Both indices are kept in range `0..2N`, so that a full queue can be distinguished from an empty queue.
+	head:AtomicUsize,
+	tail:AtomicUsize,
+	split:AtomicBool,
+	buff:UnsafeCell<MaybeUninit<[T;N]>>
+}
+
+unsafe impl<const N:usize,T:Send> Sync for Queue<N,T> {}
+
+impl<const N:usize,T> Default for Queue<N,T>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<const N:usize,T> Queue<N,T>
+{
+	/// Constructs a new, empty `Queue<N,T>`.
+	/// 
+	/// The `new` method will not zero the buffer, so the initial operation is very inexpensive.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::spsc::Queue;
+	/// let q:Queue<8,u64>=Queue::new();
+	/// assert!(q.is_empty());
+	/// ```
+	pub const fn new()->Self
+	{
+		Self
+		{
+			head:AtomicUsize::new(0),
+			tail:AtomicUsize::new(0),
+			split:AtomicBool::new(false),
+			buff:UnsafeCell::new(MaybeUninit::uninit())
+		}
+	}
+
+	// Advances an index in range `0..2N`.
+	const fn next_index(index:usize)->usize
+	{
+		if index+1>=N*2 {0} else {index+1}
+	}
+
+	// Translates an index in range `0..2N` into a slot in the buffer.
+	const fn slot(index:usize)->usize
+	{
+		if index>=N {index-N} else {index}
+	}
+
+	const fn distance(head:usize,tail:usize)->usize
+	{
+		if tail>=head {tail-head} else {tail+N*2-head}
+	}
+
+	fn slot_ptr(&self,index:usize)->*mut T
+	{
+		unsafe
+		{
+			self.buff.get().cast::<T>().add(Self::slot(index))
+		}
+	}
+
+	/// Returns the number of values in the queue. \
+	/// If the queue is being accessed concurrently, the result is only a snapshot.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::spsc::Queue;
+	/// let mut q:Queue<8,u64>=Queue::new();
+	/// let (mut p,_c)=q.split();
+	/// p.enqueue(1).unwrap();
+	/// assert_eq!(p.len(),1);
+	/// ```
+	pub fn len(&self)->usize
+	{
+		let head=self.head.load(Ordering::Acquire);
+		let tail=self.tail.load(Ordering::Acquire);
+		Self::distance(head,tail)
+	}
+
+	/// Checks if the queue is empty. \
+	/// If the queue is being accessed concurrently, the result is only a snapshot.
+	pub fn is_empty(&self)->bool
+	{
+		self.len()==0
+	}
+
+	/// Checks if the queue is full. \
+	/// If the queue is being accessed concurrently, the result is only a snapshot.
+	pub fn is_full(&self)->bool
+	{
+		self.len()==N
+	}
+
+	/// Returns the capacity of the queue.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::spsc::Queue;
+	/// let q:Queue<12,u64>=Queue::new();
+	/// assert_eq!(q.capacity(),12);
+	/// ```
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+
+	/// Splits the queue into a `Producer` and a `Consumer`. \
+	/// The exclusive borrow guarantees that there is only one producer and one consumer.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::spsc::Queue;
+	/// let mut q:Queue<8,u64>=Queue::new();
+	/// let (mut p,mut c)=q.split();
+	/// std::thread::scope(|s|
+	/// {
+	///     s.spawn(move || for i in 0..100 {while p.enqueue(i).is_err() {std::thread::yield_now();}});
+	///     for i in 0..100 {assert_eq!(loop {if let Some(v)=c.dequeue() {break v} std::thread::yield_now();},i);}
+	/// });
+	/// ```
+	pub fn split(&mut self)->(Producer<'_,N,T>,Consumer<'_,N,T>)
+	{
+		let queue=&*self;
+		(Producer{queue,phantom:PhantomData},Consumer{queue,phantom:PhantomData})
+	}
+
+	/// Splits the queue into a `Producer` and a `Consumer` through a shared reference. \
+	/// This method is useful if the queue is placed in a `static`. \
+	/// Returns `None` if the queue has already been split.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::spsc::Queue;
+	/// static Q:Queue<8,u64>=Queue::new();
+	/// assert!(Q.try_split().is_some());
+	/// assert!(Q.try_split().is_none());
+	/// ```
+	pub fn try_split(&self)->Option<(Producer<'_,N,T>,Consumer<'_,N,T>)>
+	{
+		if self.split.swap(true,Ordering::AcqRel)
+		{
+			None
+		}
+		else
+		{
+			Some((Producer{queue:self,phantom:PhantomData},Consumer{queue:self,phantom:PhantomData}))
+		}
+	}
+
+	// Only the producer may call this routine.
+	fn enqueue(&self,v:T)->Result<(),CapacityError<T>>
+	{
+		let tail=self.tail.load(Ordering::Relaxed);
+		let head=self.head.load(Ordering::Acquire);
+		if Self::distance(head,tail)>=N
+		{
+			Err(CapacityError::new(v))
+		}
+		else
+		{
+			unsafe
+			{
+				ptr::write(self.slot_ptr(tail),v);
+			}
+			// Publish the value to the consumer.
+			self.tail.store(Self::next_index(tail),Ordering::Release);
+			Ok(())
+		}
+	}
+
+	// Only the consumer may call this routine.
+	fn dequeue(&self)->Option<T>
+	{
+		let head=self.head.load(Ordering::Relaxed);
+		let tail=self.tail.load(Ordering::Acquire);
+		if head==tail
+		{
+			None
+		}
+		else
+		{
+			let v=unsafe{ptr::read(self.slot_ptr(head))};
+			// Release the slot to the producer.
+			self.head.store(Self::next_index(head),Ordering::Release);
+			Some(v)
+		}
+	}
+
+	// Only the consumer may call this routine.
+	fn peek(&self)->Option<&T>
+	{
+		let head=self.head.load(Ordering::Relaxed);
+		let tail=self.tail.load(Ordering::Acquire);
+		if head==tail
+		{
+			None
+		}
+		else
+		{
+			unsafe
+			{
+				Some(&*self.slot_ptr(head))
+			}
+		}
+	}
+}
+
+impl<const N:usize,T> Drop for Queue<N,T>
+{
+	fn drop(&mut self)
+	{
+		// Force drop every item.
+		while self.dequeue().is_some() {}
+	}
+}
+
+impl<const N:usize,T> fmt::Debug for Queue<N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_struct("Queue").field("len",&self.len()).field("capacity",&N).finish()
+	}
+}
+
+/// The producer handle of a `Queue`. \
+/// Only the producer can put values into the queue. \
+/// The handle can be sent to another thread, but it cannot be shared between threads.
+pub struct Producer<'a,const N:usize,T>
+{
+	queue:&'a Queue<N,T>,
+	// Opt out of `Sync`, so that only one thread at a time may act as the producer.
+	phantom:PhantomData<Cell<()>>
+}
+
+impl<'a,const N:usize,T> Producer<'a,N,T>
+{
+	/// Put value `v` to the end of the queue. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the queue is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::spsc::Queue;
+	/// let mut q:Queue<1,u64>=Queue::new();
+	/// let (mut p,_c)=q.split();
+	/// assert!(p.enqueue(1).is_ok());
+	/// assert_eq!(p.enqueue(2).unwrap_err().into_inner(),2);
+	/// ```
+	pub fn enqueue(&mut self,v:T)->Result<(),CapacityError<T>>
+	{
+		self.queue.enqueue(v)
+	}
+
+	/// Checks if the queue is full. \
+	/// If this method returns `false`, the next `enqueue` is guaranteed to succeed.
+	pub fn is_full(&self)->bool
+	{
+		self.queue.is_full()
+	}
+
+	/// Returns the number of values in the queue.
+	pub fn len(&self)->usize
+	{
+		self.queue.len()
+	}
+
+	/// Checks if the queue is empty.
+	pub fn is_empty(&self)->bool
+	{
+		self.queue.is_empty()
+	}
+
+	/// Returns the capacity of the queue.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+}
+
+impl<'a,const N:usize,T> fmt::Debug for Producer<'a,N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_struct("Producer").field("queue",self.queue).finish()
+	}
+}
+
+/// The consumer handle of a `Queue`. \
+/// Only the consumer can take values out of the queue. \
+/// The handle can be sent to another thread, but it cannot be shared between threads,
+/// because `peek` method returns a reference to the front value.
+/// 
+/// ```compile_fail
+/// use core::cell::Cell;
+/// use static_collections::spsc::Consumer;
+/// fn assert_sync<S:Sync>() {}
+/// assert_sync::<Consumer<'static,4,Cell<u32>>>();
+/// ```
+pub struct Consumer<'a,const N:usize,T>
+{
+	queue:&'a Queue<N,T>,
+	// Opt out of `Sync`, so that two threads cannot peek at the same value.
+	phantom:PhantomData<Cell<()>>
+}
+
+impl<'a,const N:usize,T> Consumer<'a,N,T>
+{
+	/// Read and remove the value at the front of the queue. \
+	/// Returns `None` if the queue is empty.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::spsc::Queue;
+	/// let mut q:Queue<4,u64>=Queue::new();
+	/// let (mut p,mut c)=q.split();
+	/// p.enqueue(1).unwrap();
+	/// assert_eq!(c.dequeue(),Some(1));
+	/// assert_eq!(c.dequeue(),None);
+	/// ```
+	pub fn dequeue(&mut self)->Option<T>
+	{
+		self.queue.dequeue()
+	}
+
+	/// Returns a reference to the value at the front of the queue without removing it.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::spsc::Queue;
+	/// let mut q:Queue<4,u64>=Queue::new();
+	/// let (mut p,mut c)=q.split();
+	/// p.enqueue(1).unwrap();
+	/// assert_eq!(c.peek(),Some(&1));
+	/// assert_eq!(c.dequeue(),Some(1));
+	/// ```
+	pub fn peek(&self)->Option<&T>
+	{
+		self.queue.peek()
+	}
+
+	/// Checks if the queue is empty. \
+	/// If this method returns `false`, the next `dequeue` is guaranteed to succeed.
+	pub fn is_empty(&self)->bool
+	{
+		self.queue.is_empty()
+	}
+
+	/// Returns the number of values in the queue.
+	pub fn len(&self)->usize
+	{
+		self.queue.len()
+	}
+
+	/// Returns the capacity of the queue.
+	pub const fn capacity(&self)->usize
+	{
+		N
+	}
+}
+
+impl<'a,const N:usize,T> fmt::Debug for Consumer<'a,N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_struct("Consumer").field("queue",self.queue).finish()
+	}
+}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use core::sync::atomic::{AtomicUsize,Ordering};
+	use std::thread;
+	use crate::spsc::Queue;
+
+	#[test] fn stress()
+	{
+		const COUNT:u64=200000;
+		let mut q:Queue<7,u64>=Queue::new();
+		let (mut p,mut c)=q.split();
+		thread::scope(|s|
+		{
+			s.spawn(move ||
+			{
+				for i in 0..COUNT
+				{
+					let mut v=i;
+					loop
+					{
+						match p.enqueue(v)
+						{
+							Ok(())=>break,
+							Err(e)=>v=e.into_inner()
+						}
+						thread::yield_now();
+					}
+				}
+			});
+			s.spawn(move ||
+			{
+				let mut expected=0;
+				while expected<COUNT
+				{
+					match c.dequeue()
+					{
+						Some(v)=>
+						{
+							assert_eq!(v,expected);
+							expected+=1;
+						}
+						None=>thread::yield_now()
+					}
+				}
+				assert_eq!(c.dequeue(),None);
+			});
+		});
+		assert!(q.is_empty());
+	}
+
+	#[test] fn static_queue()
+	{
+		static Q:Queue<4,usize>=Queue::new();
+		let (mut p,mut c)=Q.try_split().unwrap();
+		assert!(Q.try_split().is_none());
+		let handle=thread::spawn(move ||
+		{
+			for i in 0..10000
+			{
+				while p.enqueue(i).is_err()
+				{
+					thread::yield_now();
+				}
+			}
+		});
+		let mut sum=0;
+		let mut n=0;
+		while n<10000
+		{
+			match c.dequeue()
+			{
+				Some(v)=>
+				{
+					sum+=v;
+					n+=1;
+				}
+				None=>thread::yield_now()
+			}
+		}
+		handle.join().unwrap();
+		assert_eq!(sum,(0..10000).sum());
+	}
+
+	#[test] fn drop()
+	{
+		struct DropCounter<'a>
+		{
+			counter:&'a AtomicUsize
+		}
+
+		impl<'a> Drop for DropCounter<'a>
+		{
+			fn drop(&mut self)
+			{
+				self.counter.fetch_add(1,Ordering::SeqCst);
+			}
+		}
+
+		let drop_count:AtomicUsize=AtomicUsize::new(0);
+		{
+			let mut q:Queue<3,DropCounter>=Queue::new();
+			let (mut p,mut c)=q.split();
+			for _ in 0..5
+			{
+				assert!(p.enqueue(DropCounter{counter:&drop_count}).is_ok());
+				assert!(p.enqueue(DropCounter{counter:&drop_count}).is_ok());
+				c.dequeue();
+				c.dequeue();
+			}
+			assert_eq!(drop_count.load(Ordering::SeqCst),10);
+			assert!(p.enqueue(DropCounter{counter:&drop_count}).is_ok());
+			assert!(p.enqueue(DropCounter{counter:&drop_count}).is_ok());
+		}
+		assert_eq!(drop_count.load(Ordering::SeqCst),12);
+	}
+}
@@ -1,8 +1,8 @@
 // The static-string module
 
-use core::{char::DecodeUtf16Error, fmt::{self, Debug, Display}, ops::{AddAssign, Deref, DerefMut}, str};
+use core::{char::DecodeUtf16Error, fmt::{self, Debug, Display}, ops::{AddAssign, Deref, DerefMut}, ptr, str};
 
-use crate::{ffi::c_str::strnlen, vec::StaticVec};
+use crate::{ffi::c_str::strnlen, vec::{CapacityError, StaticVec}};
 
 #[derive(Debug)]
 pub enum InsertError
@@ -12,6 +12,14 @@ pub enum InsertError
 	Utf16Error(DecodeUtf16Error)
 }
 
+impl<T> From<CapacityError<T>> for InsertError
+{
+	fn from(_:CapacityError<T>)->Self
+	{
+		Self::InsufficientSpace
+	}
+}
+
 /// The `StaticString` type is a fixed-capacity UTF-8 string object. \
 /// To estimate length `N` you need, consider the following UTF-8 facts:
 /// - 1-byte: English letters and basic punctuations.
@@ -52,6 +60,34 @@ impl<const N:usize> StaticString<N>
 		}
 	}
 
+	/// Creates a new `StaticString` from a string slice. \
+	/// Unlike `From<&str>`, this method can be evaluated in constant contexts, so it can initialize a `static` item.
+	/// 
+	/// # Panic
+	/// If the string slice exceeds the capacity, this method would panic. \
+	/// In a constant context, the panic becomes a compile error.
+	/// 
+	/// # Examples
+	/// ```
+	/// use static_collections::string::StaticString;
+	/// static GREETING:StaticString<16>=StaticString::from_str_const("Hello, World!");
+	/// assert_eq!(GREETING.as_str(),"Hello, World!");
+	/// ```
+	/// 
+	/// ```compile_fail
+	/// use static_collections::string::StaticString;
+	/// static GREETING:StaticString<4>=StaticString::from_str_const("Hello, World!");
+	/// ```
+	pub const fn from_str_const(s:&str)->Self
+	{
+		let mut r=Self::new();
+		if r.push_str(s).is_err()
+		{
+			panic!("String is too large!");
+		}
+		r
+	}
+
 	/// Returns a byte slice of this `StaticString`'s contents.
 	/// 
 	/// # Examples
@@ -121,7 +157,19 @@ impl<const N:usize> StaticString<N>
 	/// s.push('!');
 	/// assert_eq!(s.as_str(),"Hello!");
 	/// ```
-	pub fn push(&mut self,ch:char)->Result<(),InsertError>
+	/// 
+	/// This method can be evaluated in constant contexts.
+	/// ```
+	/// use static_collections::string::StaticString;
+	/// static BANNER:StaticString<16>=
+	/// {
+	///     let mut s=StaticString::from_str_const("v1.0");
+	///     assert!(s.push('✓').is_ok());
+	///     s
+	/// };
+	/// assert_eq!(BANNER.as_str(),"v1.0✓");
+	/// ```
+	pub const fn push(&mut self,ch:char)->Result<(),InsertError>
 	{
 		let ch_len=ch.len_utf8();
 		let insertion_index=self.len();
@@ -135,7 +183,7 @@ impl<const N:usize> StaticString<N>
 			{
 				self.internal.force_resize(insertion_index+ch_len);
 			}
-			ch.encode_utf8(&mut self.internal[insertion_index..]);
+			ch.encode_utf8(self.internal.as_mut_slice().split_at_mut(insertion_index).1);
 			Ok(())
 		}
 	}
@@ -149,11 +197,10 @@ impl<const N:usize> StaticString<N>
 	/// s.push_str(", World!");
 	/// assert_eq!(s.as_str(),"Hello, World!");
 	/// ```
-	pub fn push_str(&mut self,string:&str)->Result<(),InsertError>
+	pub const fn push_str(&mut self,string:&str)->Result<(),InsertError>
 	{
-		let str_len=string.len();
-		let insertion_index=self.len();
-		if insertion_index+str_len>N
+		let length=self.len();
+		if length+string.len()>N
 		{
 			Err(InsertError::InsufficientSpace)
 		}
@@ -161,10 +208,9 @@ impl<const N:usize> StaticString<N>
 		{
 			unsafe
 			{
-				self.internal.force_resize(insertion_index+str_len);
+				ptr::copy_nonoverlapping(string.as_ptr(),self.internal.as_mut_ptr().add(length),string.len());
+				self.internal.force_resize(length+string.len());
 			}
-			let dest_buff=&mut self.internal[insertion_index..];
-			dest_buff.copy_from_slice(string.as_bytes());
 			Ok(())
 		}
 	}
@@ -542,7 +588,7 @@ impl<const N:usize> StaticString<N>
 	/// let mut s:StaticString<64>=StaticString::from("Hello, World!");
 	/// assert_eq!(s.len(),13);
 	/// ```
-	#[inline(always)] pub fn len(&self)->usize
+	#[inline(always)] pub const fn len(&self)->usize
 	{
 		self.internal.len()
 	}
@@ -555,7 +601,7 @@ impl<const N:usize> StaticString<N>
 	/// let s:StaticString<128>=StaticString::new();
 	/// assert_eq!(s.capacity(),128);
 	/// ```
-	#[inline(always)] pub fn capacity(&self)->usize
+	#[inline(always)] pub const fn capacity(&self)->usize
 	{
 		N
 	}
@@ -570,7 +616,7 @@ impl<const N:usize> StaticString<N>
 	/// s=StaticString::new();
 	/// assert_eq!(s.is_empty(),true);
 	/// ```
-	#[inline(always)] pub fn is_empty(&self)->bool
+	#[inline(always)] pub const fn is_empty(&self)->bool
 	{
 		self.len()==0
 	}
@@ -613,12 +659,7 @@ impl<const N:usize> From<&str> for StaticString<N>
 {
 	fn from(value:&str)->Self
 	{
-		let mut s=Self::default();
-		if s.insert_str(0,value).is_err()
-		{
-			panic!("String is too large!");
-		}
-		s
+		Self::from_str_const(value)
 	}
 }
 
@@ -678,6 +719,91 @@ impl<const N:usize> PartialEq<&str> for StaticString<N>
 	}
 }
 
+#[cfg(feature = "serde")]
+impl<const N:usize> serde::Serialize for StaticString<N>
+{
+	fn serialize<S:serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(self.as_str())
+	}
+}
+
+// Deserializing a string longer than `N` bytes fails with an `invalid_length` error.
+#[cfg(feature = "serde")]
+impl<'de,const N:usize> serde::Deserialize<'de> for StaticString<N>
+{
+	fn deserialize<D:serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+	{
+		deserializer.deserialize_str(StaticStringVisitor)
+	}
+}
+
+#[cfg(feature = "serde")]
+struct StaticStringVisitor<const N:usize>;
+
+#[cfg(feature = "serde")]
+impl<'de,const N:usize> serde::de::Visitor<'de> for StaticStringVisitor<N>
+{
+	type Value = StaticString<N>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(formatter,"a string of at most {N} bytes")
+	}
+
+	fn visit_str<E:serde::de::Error>(self, v: &str) -> Result<Self::Value, E>
+	{
+		let mut s=StaticString::new();
+		match s.push_str(v)
+		{
+			Ok(())=>Ok(s),
+			Err(_)=>Err(E::invalid_length(v.len(),&self))
+		}
+	}
+
+	fn visit_bytes<E:serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E>
+	{
+		match str::from_utf8(v)
+		{
+			Ok(v)=>self.visit_str(v),
+			Err(_)=>Err(E::invalid_value(serde::de::Unexpected::Bytes(v),&self))
+		}
+	}
+}
+
+/// This routine is the internal helper function for `format_static` macro. Do not use directly. \
+/// It returns the number of bytes of the literal pieces in the format string `fmt`,
+/// which is the lower bound of the length of the formatted string.
+#[doc(hidden)] pub const fn _fmt_literal_len(fmt:&str)->usize
+{
+	let b=fmt.as_bytes();
+	let mut i:usize=0;
+	let mut len:usize=0;
+	while i<b.len()
+	{
+		if b[i]==b'{' && i+1<b.len() && b[i+1]!=b'{'
+		{
+			// Skip the placeholder.
+			while i<b.len() && b[i]!=b'}'
+			{
+				i+=1;
+			}
+			i+=1;
+		}
+		else
+		{
+			// An escaped brace `{{` or `}}` produces a single brace.
+			if (b[i]==b'{' || b[i]==b'}') && i+1<b.len() && b[i+1]==b[i]
+			{
+				i+=1;
+			}
+			len+=1;
+			i+=1;
+		}
+	}
+	len
+}
+
 /// This routine is the internal helper function for `format_static` macro. Do not use directly.
 pub fn _static_fmt_str<const N:usize>(args:fmt::Arguments)->Result<StaticString<N>,InsertError>
 {
@@ -691,16 +817,57 @@ pub fn _static_fmt_str<const N:usize>(args:fmt::Arguments)->Result<StaticString<
 
 /// The `format_static` macro builds a static string via format.
 /// 
+/// If the literal pieces of the format string alone exceed the capacity, the macro fails to compile.
+/// Otherwise, the formatted length is checked at runtime.
+/// 
 /// # Example
 /// ```
 /// use static_collections::*;
 /// let s=format_static!(256,"Hello, {}!","World");
 /// assert_eq!(s.unwrap(),"Hello, World!");
+/// let s=format_static!(8,"{}{{}}","Hello, World!");
+/// assert!(s.is_err());
+/// ```
+/// 
+/// ```compile_fail
+/// use static_collections::*;
+/// let s=format_static!(4,"Hello, {}!","World");
 /// ```
 #[macro_export] macro_rules! format_static
 {
+	($len:expr,$fmt:literal $($arg:tt)*)=>
+	{
+		{
+			const{assert!($crate::string::_fmt_literal_len($fmt)<=$len,"The format string exceeds capacity!")};
+			$crate::string::_static_fmt_str::<$len>(format_args!($fmt $($arg)*))
+		}
+	};
 	($len:expr,$($arg:tt)*)=>
 	{
 		$crate::string::_static_fmt_str::<$len>(format_args!($($arg)*))
 	};
 }
+
+#[cfg(all(test,feature = "serde"))] mod test
+{
+	extern crate std;
+
+	use std::string::ToString;
+	use serde::{Deserialize, de::value::{BytesDeserializer, Error, StrDeserializer}};
+	use serde_test::{Token, assert_ser_tokens};
+	use crate::string::StaticString;
+
+	#[test] fn serde()
+	{
+		let s:StaticString<8>=StaticString::from("Hello");
+		assert_ser_tokens(&s,&[Token::Str("Hello")]);
+		let d=StaticString::<8>::deserialize(StrDeserializer::<Error>::new("Hello")).unwrap();
+		assert_eq!(d,"Hello");
+		let d=StaticString::<8>::deserialize(BytesDeserializer::<Error>::new(b"Hello")).unwrap();
+		assert_eq!(d,"Hello");
+		let e=StaticString::<4>::deserialize(StrDeserializer::<Error>::new("Hello")).err().unwrap();
+		assert_eq!(e.to_string(),"invalid length 5, expected a string of at most 4 bytes");
+		let e=StaticString::<4>::deserialize(BytesDeserializer::<Error>::new(b"\xFF")).err().unwrap();
+		assert_eq!(e.to_string(),"invalid value: byte array, expected a string of at most 4 bytes");
+	}
+}
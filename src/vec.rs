@@ -1,6 +1,66 @@
 // The static-vector module
 
-use core::{mem::MaybeUninit, ops::{Deref, DerefMut}, ptr, slice};
+use core::{borrow::{Borrow, BorrowMut}, cmp::Ordering, fmt, hash::{Hash, Hasher}, iter::FusedIterator, mem::{ManuallyDrop, MaybeUninit}, ops::{Bound, Deref, DerefMut, Range, RangeBounds}, ptr, slice};
+
+use crate::slice_vec::SliceVec;
+
+/// This error is used to indicate that a static collection has insufficient capacity. \
+/// The rejected value is handed back to the caller, so no data is silently lost.
+#[derive(PartialEq, Debug)]
+pub struct CapacityError<T=()>
+{
+	element:T
+}
+
+impl<T> CapacityError<T>
+{
+	/// Constructs a new `CapacityError` which holds the rejected value `element`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::vec::CapacityError;
+	/// let e=CapacityError::new(1234);
+	/// assert_eq!(e.element(),&1234);
+	/// ```
+	pub const fn new(element:T)->Self
+	{
+		Self
+		{
+			element
+		}
+	}
+
+	/// Returns the rejected value back to the caller.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// let mut v:StaticVec<1,u64>=StaticVec::new();
+	/// v.push(1234);
+	/// let e=v.try_push(5678).unwrap_err();
+	/// assert_eq!(e.into_inner(),5678);
+	/// ```
+	pub fn into_inner(self)->T
+	{
+		self.element
+	}
+
+	/// Returns a reference to the rejected value.
+	pub const fn element(&self)->&T
+	{
+		&self.element
+	}
+}
+
+impl<T> fmt::Display for CapacityError<T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f,"insufficient capacity")
+	}
+}
+
+impl<T:fmt::Debug> core::error::Error for CapacityError<T> {}
 
 #[derive(Debug)]
 pub struct StaticVec<const N:usize,T>
@@ -9,15 +69,19 @@ pub struct StaticVec<const N:usize,T>
 	buff:MaybeUninit<[T;N]>
 }
 
-impl<const N:usize,T:Copy> Clone for StaticVec<N,T>
+impl<const N:usize,T:Clone> Clone for StaticVec<N,T>
 {
+	/// Clones the initialized values one by one. \
+	/// If cloning a value panics, the values cloned so far are dropped.
 	fn clone(&self) -> Self
 	{
-		Self
+		let mut v=Self::new();
+		for item in self.iter()
 		{
-			length:self.length,
-			buff:self.buff
+			// The length grows with every clone, so the new static vector drops exactly the cloned values on panic.
+			v.push(item.clone());
 		}
+		v
 	}
 }
 
@@ -81,8 +145,36 @@ impl<const N:usize,T> StaticVec<N,T>
 		}
 	}
 
+	/// Returns a `SliceVec` view of the static vector, whose capacity is `N`. \
+	/// Changes made through the view are visible in the static vector after the view is dropped. \
+	/// If the view is leaked, the values in the static vector are leaked as well.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let mut v:StaticVec<8,u64>=vec_static![1,2];
+	/// let mut s=v.as_slice_vec();
+	/// s.push(3);
+	/// assert_eq!(s.capacity(),8);
+	/// drop(s);
+	/// assert_eq!(v.as_slice(),&[1,2,3]);
+	/// ```
+	pub fn as_slice_vec(&mut self)->SliceVec<'_,T>
+	{
+		// `MaybeUninit<[T;N]>` has the same layout as `[MaybeUninit<T>;N]`.
+		let buff=unsafe{&mut *(&raw mut self.buff as *mut [MaybeUninit<T>;N])};
+		unsafe
+		{
+			SliceVec::from_raw_parts(buff,&mut self.length)
+		}
+	}
+
 	/// Put value `v` to the end of static vector.
 	/// 
+	/// # Panic
+	/// If the static vector is full, this method would panic. \
+	/// Use `try_push` method if you would like to handle the overflow.
+	/// 
 	/// # Example
 	/// ```
 	/// use static_collections::vec::StaticVec;
@@ -92,17 +184,62 @@ impl<const N:usize,T> StaticVec<N,T>
 	/// v.push(4567);
 	/// assert_eq!(v.as_slice(),&[1234,4567]);
 	/// ```
-	pub fn push(&mut self,v:T)
+	/// 
+	/// This method can be evaluated in constant contexts, so a `static` vector can be built without lazy initialization.
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// static SQUARES:StaticVec<8,u64>=
+	/// {
+	///     let mut v=StaticVec::new();
+	///     let mut i=0;
+	///     while i<5
+	///     {
+	///         v.push(i*i);
+	///         i+=1;
+	///     }
+	///     v
+	/// };
+	/// assert_eq!(SQUARES.as_slice(),&[0,1,4,9,16]);
+	/// ```
+	pub const fn push(&mut self,v:T)
+	{
+		if self.length>=N
+		{
+			panic!("StaticVec buffer overflow!");
+		}
+		unsafe
+		{
+			let vector=self.buff.assume_init_mut();
+			// Use ptr::write to avoid `Drop` trait requirement.
+			ptr::write(&raw mut vector[self.length],v);
+		}
+		self.length+=1;
+	}
+
+	/// Put value `v` to the end of static vector. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static vector is full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// let mut v:StaticVec<2,u64>=StaticVec::new();
+	/// assert!(v.try_push(1234).is_ok());
+	/// assert!(v.try_push(4567).is_ok());
+	/// assert_eq!(v.try_push(7890).unwrap_err().into_inner(),7890);
+	/// assert_eq!(v.as_slice(),&[1234,4567]);
+	/// ```
+	pub const fn try_push(&mut self,v:T)->Result<(),CapacityError<T>>
 	{
+		// `push` cannot be built on `try_push`, because a returned `Result` cannot be dropped in constant contexts.
+		// Therefore, the capacity is checked here before `push` is called.
 		if self.length<N
 		{
-			unsafe
-			{
-				let vector=self.buff.assume_init_mut();
-				// Use ptr::write to avoid `Drop` trait requirement.
-				ptr::write(&raw mut vector[self.length],v);
-			}
-			self.length+=1;
+			self.push(v);
+			Ok(())
+		}
+		else
+		{
+			Err(CapacityError::new(v))
 		}
 	}
 
@@ -115,7 +252,7 @@ impl<const N:usize,T> StaticVec<N,T>
 	/// v.push(1234);
 	/// assert_eq!(v.pop(),Some(1234));
 	/// ```
-	pub fn pop(&mut self)->Option<T>
+	pub const fn pop(&mut self)->Option<T>
 	{
 		if self.length>0
 		{
@@ -134,6 +271,10 @@ impl<const N:usize,T> StaticVec<N,T>
 
 	/// Insert value `v` to a specific location of static vector.
 	/// 
+	/// # Panic
+	/// If the static vector is full, or if `index>len`, this method would panic. \
+	/// Use `try_insert` method if you would like to handle the overflow.
+	/// 
 	/// # Example
 	/// ```
 	/// use static_collections::vec::StaticVec;
@@ -145,7 +286,35 @@ impl<const N:usize,T> StaticVec<N,T>
 	/// ```
 	pub fn insert(&mut self,index:usize,v:T)
 	{
-		if self.length<N && index<=self.length
+		if self.try_insert(index,v).is_err()
+		{
+			panic!("StaticVec buffer overflow!");
+		}
+	}
+
+	/// Insert value `v` to a specific location of static vector. \
+	/// Returns `Err(CapacityError<T>)` with the value `v` if the static vector is full.
+	/// 
+	/// # Panic
+	/// If `index>len`, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// let mut v:StaticVec<3,u64>=StaticVec::new();
+	/// v.push(1234);
+	/// v.push(4567);
+	/// assert!(v.try_insert(1,2333).is_ok());
+	/// assert_eq!(v.try_insert(0,7890).unwrap_err().into_inner(),7890);
+	/// assert_eq!(v.as_slice(),&[1234,2333,4567]);
+	/// ```
+	pub fn try_insert(&mut self,index:usize,v:T)->Result<(),CapacityError<T>>
+	{
+		if index>self.length
+		{
+			panic!("Insertion index {index} is out of length {}!",self.length);
+		}
+		if self.length<N
 		{
 			// Use unsafe codes to avoid `Copy` trait.
 			unsafe
@@ -155,6 +324,11 @@ impl<const N:usize,T> StaticVec<N,T>
 				ptr::write(p,v);
 			}
 			self.length+=1;
+			Ok(())
+		}
+		else
+		{
+			Err(CapacityError::new(v))
 		}
 	}
 
@@ -191,6 +365,60 @@ impl<const N:usize,T> StaticVec<N,T>
 		}
 	}
 
+	/// Clones and appends all elements in the `other` slice to the end of static vector. \
+	/// Returns `Err(CapacityError<&[T]>)` with the `other` slice if the static vector cannot hold all elements.
+	/// In this case, the static vector is not modified.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// let mut v:StaticVec<4,u64>=StaticVec::new();
+	/// assert!(v.try_extend_from_slice(&[1,2,3]).is_ok());
+	/// assert_eq!(v.try_extend_from_slice(&[4,5]).unwrap_err().into_inner(),&[4,5]);
+	/// assert_eq!(v.as_slice(),&[1,2,3]);
+	/// ```
+	pub fn try_extend_from_slice<'a>(&mut self,other:&'a [T])->Result<(),CapacityError<&'a [T]>> where T:Clone
+	{
+		if other.len()>N-self.length
+		{
+			Err(CapacityError::new(other))
+		}
+		else
+		{
+			for item in other
+			{
+				unsafe
+				{
+					// Increase the length on every iteration in case `clone` panics.
+					ptr::write(self.as_mut_ptr().add(self.length),item.clone());
+				}
+				self.length+=1;
+			}
+			Ok(())
+		}
+	}
+
+	/// Clones and appends all elements in the `other` slice to the end of static vector.
+	/// 
+	/// # Panic
+	/// If the static vector cannot hold all elements, this method would panic. \
+	/// Use `try_extend_from_slice` method if you would like to handle the overflow.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// let mut v:StaticVec<4,u64>=StaticVec::new();
+	/// v.extend_from_slice(&[1,2,3]);
+	/// assert_eq!(v.as_slice(),&[1,2,3]);
+	/// ```
+	pub fn extend_from_slice(&mut self,other:&[T]) where T:Clone
+	{
+		if self.try_extend_from_slice(other).is_err()
+		{
+			panic!("StaticVec buffer overflow!");
+		}
+	}
+
 	/// Shortens this static-vector to the specified `new_len`.
 	/// 
 	/// # Examples
@@ -252,7 +480,7 @@ impl<const N:usize,T> StaticVec<N,T>
 	/// v.clear();
 	/// assert!(v.is_empty());
 	/// ```
-	pub fn is_empty(&self)->bool
+	pub const fn is_empty(&self)->bool
 	{
 		self.len()==0
 	}
@@ -285,160 +513,1171 @@ impl<const N:usize,T> StaticVec<N,T>
 		N
 	}
 
-	/// This routine is a helper routine that constructs a static-vector for the `vec_static!` macro. \
-	/// This routine assigns the `value` specified at location `index` in the static-vector.
-	/// 
-	/// # Safety
-	/// Do not use this routine on your own. This routine is only supposed to be called by `vec_static!` macro, \
-	/// and the `index` is allowed to go beyond the `self.len()` limit. That's why this routine is unsafe.
+	/// Appends all values yielded by `iter` to the end of static vector. \
+	/// Returns `Err(CapacityError<T>)` with the first rejected value if the static vector is full.
+	/// In this case, values appended before the rejected one are kept in the static vector.
 	/// 
-	/// # Panic
-	/// If `index>=N`, internal assertion would fail.
-	pub const unsafe fn force_assign(&mut self,index:usize,value:T)
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// let mut v:StaticVec<4,u64>=StaticVec::new();
+	/// assert!(v.try_extend([1,2]).is_ok());
+	/// assert_eq!(v.try_extend([3,4,5,6]).unwrap_err().into_inner(),5);
+	/// assert_eq!(v.as_slice(),&[1,2,3,4]);
+	/// ```
+	pub fn try_extend<I:IntoIterator<Item=T>>(&mut self,iter:I)->Result<(),CapacityError<T>>
 	{
-		assert!(index<N);
-		unsafe
+		for item in iter
 		{
-			ptr::write(self.buff.assume_init_mut().as_mut_ptr().add(index),value);
+			self.try_push(item)?;
 		}
+		Ok(())
 	}
 
-	/// This routine is a helper routine that constructs a static-vector for the `vec_static!` macro. \
-	/// This routine resizes the static vector with specified length. \
-	/// Should you wish to use `force_resize` method, read the `# Safety` and `# Panic` chapters.
-	/// 
-	/// # Safety
-	/// If `index>self.length`, the static-vector may end with undefined contents. \
-	/// You should get rid of these undefined contents after you force resized this vector.
+	/// Constructs a new static vector from the values yielded by `iter`. \
+	/// Returns `Err(CapacityError<T>)` with the first rejected value if `iter` yields more than `N` values.
 	/// 
-	/// # Panic
-	/// If `length>N`, internal assertion would fail.
-	pub const unsafe fn force_resize(&mut self,length:usize)
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// let v:StaticVec<4,u64>=StaticVec::try_from_iter(1..=4).unwrap();
+	/// assert_eq!(v.as_slice(),&[1,2,3,4]);
+	/// let e=StaticVec::<4,u64>::try_from_iter(1..=5).unwrap_err();
+	/// assert_eq!(e.into_inner(),5);
+	/// ```
+	pub fn try_from_iter<I:IntoIterator<Item=T>>(iter:I)->Result<Self,CapacityError<T>>
 	{
-		assert!(length<=N,"The new length exceeds capacity!");
-		self.length=length;
+		let mut v=Self::new();
+		v.try_extend(iter)?;
+		Ok(v)
 	}
-}
 
-impl<const N:usize,T> Drop for StaticVec<N,T>
-{
-	fn drop(&mut self)
+	/// Removes the specified `range` from the static vector, returning all removed values as an iterator. \
+	/// If the iterator is dropped before being fully consumed, the remaining removed values are dropped.
+	/// 
+	/// # Panic
+	/// If the starting point is greater than the end point, or if the end point is greater than the length, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let mut v:StaticVec<8,u64>=vec_static![1,2,3,4,5];
+	/// let mut d=v.drain(1..3);
+	/// assert_eq!(d.next(),Some(2));
+	/// assert_eq!(d.next(),Some(3));
+	/// assert_eq!(d.next(),None);
+	/// drop(d);
+	/// assert_eq!(v.as_slice(),&[1,4,5]);
+	/// ```
+	pub fn drain<R:RangeBounds<usize>>(&mut self,range:R)->Drain<'_,N,T>
 	{
-		self.clear();
+		let old_len=self.length;
+		let r=resolve_range(range,old_len);
+		// Shorten the static vector first, so leaking the `Drain` would not expose moved values.
+		self.length=r.start;
+		Drain
+		{
+			vec:self,
+			index:r.start,
+			end:r.end,
+			tail_start:r.end,
+			tail_len:old_len-r.end
+		}
 	}
-}
-
-impl<const N:usize,T> Deref for StaticVec<N,T>
-{
-	type Target = [T];
 
-	fn deref(&self) -> &Self::Target
+	/// Retains only the values specified by the predicate `f`. \
+	/// All values `e` for which `f(&e)` returns `false` are removed and dropped.
+	/// This method operates in place and preserves the order of retained values.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let mut v:StaticVec<8,u64>=vec_static![1,2,3,4,5,6];
+	/// v.retain(|x| x%2==0);
+	/// assert_eq!(v.as_slice(),&[2,4,6]);
+	/// ```
+	pub fn retain<F:FnMut(&T)->bool>(&mut self,mut f:F)
 	{
-		self.as_slice()
+		self.retain_mut(|x| f(x));
 	}
-}
 
-impl<const N:usize,T> DerefMut for StaticVec<N,T>
-{
-	fn deref_mut(&mut self) -> &mut Self::Target
+	/// Retains only the values specified by the predicate `f`, passing a mutable reference to it. \
+	/// All values `e` for which `f(&mut e)` returns `false` are removed and dropped.
+	/// This method operates in place and preserves the order of retained values.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let mut v:StaticVec<8,u64>=vec_static![1,2,3,4,5,6];
+	/// v.retain_mut(|x| {*x*=10; *x<=30});
+	/// assert_eq!(v.as_slice(),&[10,20,30]);
+	/// ```
+	pub fn retain_mut<F:FnMut(&mut T)->bool>(&mut self,mut f:F)
 	{
-		self.as_mut_slice()
-	}
-}
-
-/// The `vec_static!` macro helps building a static-vector easily,
-/// similar to the `vec!` macro in `std`/`alloc` crate.
-/// 
-/// # Example
-/// ```
-/// use static_collections::{vec_static,vec::StaticVec};
-/// let a:StaticVec<12,u64>=vec_static![4;7];
-/// assert_eq!(a.as_slice(),&[4;7]);
-/// let b:StaticVec<16,u32>=vec_static![1,2,3,4,5,6,7,8];
-/// assert_eq!(b.as_slice(),&[1,2,3,4,5,6,7,8]);
-/// ```
-#[macro_export] macro_rules! vec_static
-{
-	()=>
-	(
-		$crate::vec::StaticVec::new()
-	);
-	($elem:expr;$len:expr)=>
-	(
+		let original=self.length;
+		let mut g=CompactGuard{vec:self,processed:0,deleted:0,original};
+		while g.processed<original
 		{
-			let mut v=StaticVec::new();
-			unsafe
+			let p=g.vec.as_mut_ptr();
+			let cur=unsafe{p.add(g.processed)};
+			if f(unsafe{&mut *cur})
 			{
-				let mut i:usize=0;
-				while i<$len
+				if g.deleted>0
 				{
-					v.force_assign(i,$elem);
-					i+=1;
+					unsafe
+					{
+						ptr::copy_nonoverlapping(cur,p.add(g.processed-g.deleted),1);
+					}
 				}
-				v.force_resize($len);
+				g.processed+=1;
 			}
-			v
-		}
-	);
-	($($x:expr),+$(,)?)=>
-	(
-		{
-			let mut v=StaticVec::new();
-			let mut index:usize=0;
-			unsafe
+			else
 			{
-				$(
-					{
-						v.force_assign(index,$x);
-						index+=1;
-					}
-				)*
-				v.force_resize(index);
+				// Account for the value before dropping it in case `drop` panics.
+				g.processed+=1;
+				g.deleted+=1;
+				unsafe
+				{
+					ptr::drop_in_place(cur);
+				}
 			}
-			v
 		}
-	);
-}
-
-#[cfg(test)] mod test
-{
-	extern crate std;
-
-	use std::println;
-	use crate::vec::StaticVec;
-
-	#[should_panic]
-	#[test] fn vec_macro_overflow()
-	{
-		let x:StaticVec<12,u64>=vec_static![1234;16];
-		println!("{x:?}")
 	}
 
-	#[test] fn clone()
+	/// Removes all but the first of consecutive values that resolve to the same key.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let mut v:StaticVec<8,u64>=vec_static![10,20,21,30,20];
+	/// v.dedup_by_key(|x| *x/10);
+	/// assert_eq!(v.as_slice(),&[10,20,30,20]);
+	/// ```
+	pub fn dedup_by_key<K:PartialEq,F:FnMut(&mut T)->K>(&mut self,mut key:F)
 	{
-		let x:StaticVec<16,u64>=vec_static![1,2,3,4,5,6,7,8,9,10];
-		assert_eq!(x.len(),10);
-		let y=x.clone();
-		assert_eq!(*y,[1,2,3,4,5,6,7,8,9,10]);
+		self.dedup_by(|a,b| key(a)==key(b));
 	}
 
-	#[test] fn drop()
+	/// Removes all but the first of consecutive values that satisfy the given equality relation. \
+	/// The `same_bucket` function is passed references to two values, in opposite order from their order in the static vector. \
+	/// If `same_bucket(a,b)` returns `true`, `a` is removed.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let mut v:StaticVec<8,u64>=vec_static![1,3,5,2,4,7];
+	/// v.dedup_by(|a,b| *a%2==*b%2);
+	/// assert_eq!(v.as_slice(),&[1,2,7]);
+	/// ```
+	pub fn dedup_by<F:FnMut(&mut T,&mut T)->bool>(&mut self,mut same_bucket:F)
 	{
-		use core::sync::atomic::{AtomicUsize,Ordering};
-
-		struct DropCounter<'a>
+		let original=self.length;
+		if original<=1
 		{
-			counter:&'a AtomicUsize
+			return;
 		}
-
-		impl<'a> Drop for DropCounter<'a>
+		let mut g=CompactGuard{vec:self,processed:1,deleted:0,original};
+		while g.processed<original
 		{
-			fn drop(&mut self)
+			let p=g.vec.as_mut_ptr();
+			let cur=unsafe{p.add(g.processed)};
+			let prev=unsafe{p.add(g.processed-g.deleted-1)};
+			if same_bucket(unsafe{&mut *cur},unsafe{&mut *prev})
 			{
-				self.counter.fetch_add(1,Ordering::SeqCst);
+				// Account for the value before dropping it in case `drop` panics.
+				g.processed+=1;
+				g.deleted+=1;
+				unsafe
+				{
+					ptr::drop_in_place(cur);
+				}
+			}
+			else
+			{
+				if g.deleted>0
+				{
+					unsafe
+					{
+						ptr::copy_nonoverlapping(cur,p.add(g.processed-g.deleted),1);
+					}
+				}
+				g.processed+=1;
+			}
+		}
+	}
+
+	/// Removes consecutive repeated values in the static vector.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let mut v:StaticVec<8,u64>=vec_static![1,2,2,3,2];
+	/// v.dedup();
+	/// assert_eq!(v.as_slice(),&[1,2,3,2]);
+	/// ```
+	pub fn dedup(&mut self) where T:PartialEq
+	{
+		self.dedup_by(|a,b| a==b);
+	}
+
+	/// Constructs a new static vector from an array. \
+	/// The array must fit in the static vector, which is checked at compile time.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// const V:StaticVec<8,u64>=StaticVec::from_array([1,2,3]);
+	/// assert_eq!(V.as_slice(),&[1,2,3]);
+	/// ```
+	/// 
+	/// An array larger than the capacity is rejected at compile time:
+	/// ```compile_fail
+	/// use static_collections::vec::StaticVec;
+	/// let v:StaticVec<2,u64>=StaticVec::from_array([1,2,3]);
+	/// ```
+	pub const fn from_array<const M:usize>(array:[T;M])->Self
+	{
+		const{assert!(M<=N,"The array is larger than the capacity of StaticVec!")};
+		let array=ManuallyDrop::new(array);
+		let mut v=Self::new();
+		// The `ManuallyDrop` wrapper guarantees the values will not be dropped twice.
+		unsafe
+		{
+			ptr::copy_nonoverlapping(&raw const array as *const T,v.as_mut_ptr(),M);
+		}
+		v.length=M;
+		v
+	}
+
+	/// Converts the static vector into an array. \
+	/// Returns `Err(Self)` with the static vector if it is not full.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let v:StaticVec<3,u64>=vec_static![1,2,3];
+	/// assert_eq!(v.into_array(),Ok([1,2,3]));
+	/// let v:StaticVec<3,u64>=vec_static![1,2];
+	/// assert_eq!(v.into_array().unwrap_err().as_slice(),&[1,2]);
+	/// ```
+	pub fn into_array(self)->Result<[T;N],Self>
+	{
+		if self.length==N
+		{
+			let v=ManuallyDrop::new(self);
+			// The `ManuallyDrop` wrapper guarantees the values will not be dropped twice.
+			Ok(unsafe{ptr::read(&v.buff).assume_init()})
+		}
+		else
+		{
+			Err(self)
+		}
+	}
+
+	/// Moves the values into a new static vector with capacity `M`. \
+	/// Returns `Err(CapacityError<Self>)` with the static vector if it has more than `M` values.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let v:StaticVec<4096,u8>=vec_static![1,2,3];
+	/// let w:StaticVec<4,u8>=v.try_into_capacity().unwrap();
+	/// assert_eq!(w.as_slice(),&[1,2,3]);
+	/// assert!(w.try_into_capacity::<2>().is_err());
+	/// ```
+	pub fn try_into_capacity<const M:usize>(self)->Result<StaticVec<M,T>,CapacityError<Self>>
+	{
+		if self.length>M
+		{
+			return Err(CapacityError::new(self));
+		}
+		let mut v=ManuallyDrop::new(self);
+		let mut w=StaticVec::new();
+		// The `ManuallyDrop` wrapper guarantees the values will not be dropped twice.
+		unsafe
+		{
+			ptr::copy_nonoverlapping(v.as_mut_ptr(),w.as_mut_ptr(),v.length);
+		}
+		w.length=v.length;
+		Ok(w)
+	}
+
+	/// Splits the static vector into two at the given index, and returns the values in `[at,len)` as a new static vector.
+	/// 
+	/// # Panic
+	/// If `at>len`, or if the new static vector cannot hold `len-at` values, this method would panic. \
+	/// Use `try_split_off` method if you would like to handle the overflow.
+	pub fn split_off<const M:usize>(&mut self,at:usize)->StaticVec<M,T>
+	{
+		match self.try_split_off(at)
+		{
+			Ok(v)=>v,
+			Err(_)=>panic!("StaticVec buffer overflow!")
+		}
+	}
+
+	/// Splits the static vector into two at the given index, and returns the values in `[at,len)` as a new static vector. \
+	/// Returns `Err(CapacityError)` if the new static vector cannot hold `len-at` values.
+	/// In this case, the static vector is not modified.
+	/// 
+	/// # Panic
+	/// If `at>len`, this method would panic.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let mut v:StaticVec<8,u64>=vec_static![1,2,3,4,5];
+	/// assert!(v.try_split_off::<2>(1).is_err());
+	/// let w:StaticVec<2,u64>=v.try_split_off(3).unwrap();
+	/// assert_eq!(v.as_slice(),&[1,2,3]);
+	/// assert_eq!(w.as_slice(),&[4,5]);
+	/// ```
+	pub fn try_split_off<const M:usize>(&mut self,at:usize)->Result<StaticVec<M,T>,CapacityError>
+	{
+		if at>self.length
+		{
+			panic!("Split index {at} is out of length {}!",self.length);
+		}
+		let count=self.length-at;
+		if count>M
+		{
+			return Err(CapacityError::new(()));
+		}
+		let mut w=StaticVec::new();
+		unsafe
+		{
+			ptr::copy_nonoverlapping(self.as_ptr().add(at),w.as_mut_ptr(),count);
+		}
+		self.length=at;
+		w.length=count;
+		Ok(w)
+	}
+
+	/// Moves all values of `other` to the end of the static vector, leaving `other` empty.
+	/// 
+	/// # Panic
+	/// If the static vector cannot hold all values, this method would panic. \
+	/// Use `try_append` method if you would like to handle the overflow.
+	pub fn append<const M:usize>(&mut self,other:&mut StaticVec<M,T>)
+	{
+		if self.try_append(other).is_err()
+		{
+			panic!("StaticVec buffer overflow!");
+		}
+	}
+
+	/// Moves all values of `other` to the end of the static vector, leaving `other` empty. \
+	/// Returns `Err(CapacityError)` if the static vector cannot hold all values.
+	/// In this case, neither static vector is modified.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::{vec_static,vec::StaticVec};
+	/// let mut v:StaticVec<4,u64>=vec_static![1,2];
+	/// let mut w:StaticVec<8,u64>=vec_static![3,4,5];
+	/// assert!(v.try_append(&mut w).is_err());
+	/// w.pop();
+	/// assert!(v.try_append(&mut w).is_ok());
+	/// assert_eq!(v.as_slice(),&[1,2,3,4]);
+	/// assert!(w.is_empty());
+	/// ```
+	pub fn try_append<const M:usize>(&mut self,other:&mut StaticVec<M,T>)->Result<(),CapacityError>
+	{
+		if self.length+other.length>N
+		{
+			return Err(CapacityError::new(()));
+		}
+		unsafe
+		{
+			ptr::copy_nonoverlapping(other.as_ptr(),self.as_mut_ptr().add(self.length),other.length);
+		}
+		self.length+=other.length;
+		other.length=0;
+		Ok(())
+	}
+
+	/// This routine is a helper routine for the `vec_static!` macro. \
+	/// This routine does nothing at runtime, but it fails to compile if `M>N`, so the capacity is checked at compile time.
+	/// 
+	/// Do not use this routine on your own. This routine is only supposed to be called by `vec_static!` macro.
+	#[doc(hidden)] pub const fn _assert_capacity<const M:usize>(&self)
+	{
+		const{assert!(M<=N,"The length exceeds capacity!")};
+	}
+
+	/// This routine is a helper routine that constructs a static-vector for the `vec_static!` macro. \
+	/// This routine assigns the `value` specified at location `index` in the static-vector.
+	/// 
+	/// # Safety
+	/// Do not use this routine on your own. This routine is only supposed to be called by `vec_static!` macro, \
+	/// and the `index` is allowed to go beyond the `self.len()` limit. That's why this routine is unsafe.
+	/// 
+	/// # Panic
+	/// If `index>=N`, internal assertion would fail.
+	pub const unsafe fn force_assign(&mut self,index:usize,value:T)
+	{
+		assert!(index<N);
+		unsafe
+		{
+			ptr::write(self.buff.assume_init_mut().as_mut_ptr().add(index),value);
+		}
+	}
+
+	/// This routine is a helper routine that constructs a static-vector for the `vec_static!` macro. \
+	/// This routine resizes the static vector with specified length. \
+	/// Should you wish to use `force_resize` method, read the `# Safety` and `# Panic` chapters.
+	/// 
+	/// # Safety
+	/// If `index>self.length`, the static-vector may end with undefined contents. \
+	/// You should get rid of these undefined contents after you force resized this vector.
+	/// 
+	/// # Panic
+	/// If `length>N`, internal assertion would fail.
+	pub const unsafe fn force_resize(&mut self,length:usize)
+	{
+		assert!(length<=N,"The new length exceeds capacity!");
+		self.length=length;
+	}
+}
+
+impl<const N:usize,T> Drop for StaticVec<N,T>
+{
+	fn drop(&mut self)
+	{
+		self.clear();
+	}
+}
+
+impl<const N:usize,T> Deref for StaticVec<N,T>
+{
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target
+	{
+		self.as_slice()
+	}
+}
+
+impl<const N:usize,T> DerefMut for StaticVec<N,T>
+{
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		self.as_mut_slice()
+	}
+}
+
+impl<const N:usize,const M:usize,T:PartialEq<U>,U> PartialEq<StaticVec<M,U>> for StaticVec<N,T>
+{
+	fn eq(&self, other: &StaticVec<M,U>) -> bool
+	{
+		self.as_slice()==other.as_slice()
+	}
+}
+
+impl<const N:usize,T:PartialEq<U>,U> PartialEq<[U]> for StaticVec<N,T>
+{
+	fn eq(&self, other: &[U]) -> bool
+	{
+		self.as_slice()==other
+	}
+}
+
+impl<const N:usize,T:PartialEq<U>,U> PartialEq<&[U]> for StaticVec<N,T>
+{
+	fn eq(&self, other: &&[U]) -> bool
+	{
+		self.as_slice()==*other
+	}
+}
+
+impl<const N:usize,const M:usize,T:PartialEq<U>,U> PartialEq<[U;M]> for StaticVec<N,T>
+{
+	fn eq(&self, other: &[U;M]) -> bool
+	{
+		self.as_slice()==other.as_slice()
+	}
+}
+
+impl<const N:usize,T:Eq> Eq for StaticVec<N,T> {}
+
+impl<const N:usize,const M:usize,T:PartialOrd> PartialOrd<StaticVec<M,T>> for StaticVec<N,T>
+{
+	/// Compares the values lexicographically, regardless of the capacities.
+	fn partial_cmp(&self, other: &StaticVec<M,T>) -> Option<Ordering>
+	{
+		self.as_slice().partial_cmp(other.as_slice())
+	}
+}
+
+impl<const N:usize,T:Ord> Ord for StaticVec<N,T>
+{
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.as_slice().cmp(other.as_slice())
+	}
+}
+
+impl<const N:usize,T:Hash> Hash for StaticVec<N,T>
+{
+	/// Hashes the values as a slice does, so it is consistent with `Borrow<[T]>`.
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		self.as_slice().hash(state);
+	}
+}
+
+impl<const N:usize,T> AsRef<[T]> for StaticVec<N,T>
+{
+	fn as_ref(&self) -> &[T]
+	{
+		self.as_slice()
+	}
+}
+
+impl<const N:usize,T> AsMut<[T]> for StaticVec<N,T>
+{
+	fn as_mut(&mut self) -> &mut [T]
+	{
+		self.as_mut_slice()
+	}
+}
+
+impl<const N:usize,T> Borrow<[T]> for StaticVec<N,T>
+{
+	fn borrow(&self) -> &[T]
+	{
+		self.as_slice()
+	}
+}
+
+impl<const N:usize,T> BorrowMut<[T]> for StaticVec<N,T>
+{
+	fn borrow_mut(&mut self) -> &mut [T]
+	{
+		self.as_mut_slice()
+	}
+}
+
+impl<'a,const N:usize,T:Clone> TryFrom<&'a [T]> for StaticVec<N,T>
+{
+	type Error = CapacityError<&'a [T]>;
+
+	/// Clones the values of a slice into a new static vector. \
+	/// Returns `Err(CapacityError<&[T]>)` with the slice if it is longer than `N`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// let v:StaticVec<4,u64>=StaticVec::try_from(&[1,2,3][..]).unwrap();
+	/// assert_eq!(v,[1,2,3]);
+	/// assert!(StaticVec::<2,u64>::try_from(&[1,2,3][..]).is_err());
+	/// ```
+	fn try_from(value: &'a [T]) -> Result<Self, Self::Error>
+	{
+		let mut v=Self::new();
+		v.try_extend_from_slice(value)?;
+		Ok(v)
+	}
+}
+
+impl<const N:usize,const M:usize,T> TryFrom<[T;M]> for StaticVec<N,T>
+{
+	type Error = CapacityError<[T;M]>;
+
+	/// Moves the values of an array into a new static vector. \
+	/// Returns `Err(CapacityError<[T;M]>)` with the array if `M>N`.
+	/// 
+	/// # Example
+	/// ```
+	/// use static_collections::vec::StaticVec;
+	/// let v:StaticVec<4,u64>=StaticVec::try_from([1,2,3]).unwrap();
+	/// assert_eq!(v,[1,2,3]);
+	/// assert_eq!(StaticVec::<2,u64>::try_from([1,2,3]).unwrap_err().into_inner(),[1,2,3]);
+	/// ```
+	fn try_from(value: [T;M]) -> Result<Self, Self::Error>
+	{
+		if M>N
+		{
+			return Err(CapacityError::new(value));
+		}
+		let mut v=Self::new();
+		v.extend(value);
+		Ok(v)
+	}
+}
+
+/// Converts a range into a `Range<usize>` bounded by `len`.
+/// 
+/// # Panic
+/// If the starting point is greater than the end point, or if the end point is greater than `len`, this routine would panic.
+pub(crate) fn resolve_range<R:RangeBounds<usize>>(range:R,len:usize)->Range<usize>
+{
+	let start=match range.start_bound()
+	{
+		Bound::Included(&s)=>s,
+		Bound::Excluded(&s)=>s.checked_add(1).expect("Range start overflows usize!"),
+		Bound::Unbounded=>0
+	};
+	let end=match range.end_bound()
+	{
+		Bound::Included(&e)=>e.checked_add(1).expect("Range end overflows usize!"),
+		Bound::Excluded(&e)=>e,
+		Bound::Unbounded=>len
+	};
+	if start>end
+	{
+		panic!("Range starts at {start} but ends at {end}!");
+	}
+	if end>len
+	{
+		panic!("Range end {end} is out of length {len}!");
+	}
+	start..end
+}
+
+// This guard is shared by `retain_mut` and `dedup_by`.
+// If the predicate panics, the unprocessed values are moved to close the gap,
+// so that no value is dropped twice or exposed after being dropped.
+struct CompactGuard<'a,const N:usize,T>
+{
+	vec:&'a mut StaticVec<N,T>,
+	processed:usize,
+	deleted:usize,
+	original:usize
+}
+
+impl<'a,const N:usize,T> Drop for CompactGuard<'a,N,T>
+{
+	fn drop(&mut self)
+	{
+		if self.deleted>0
+		{
+			unsafe
+			{
+				let p=self.vec.as_mut_ptr();
+				ptr::copy(p.add(self.processed),p.add(self.processed-self.deleted),self.original-self.processed);
+			}
+		}
+		self.vec.length=self.original-self.deleted;
+	}
+}
+
+impl<const N:usize,T> Extend<T> for StaticVec<N,T>
+{
+	/// Appends all values yielded by `iter` to the end of static vector.
+	/// 
+	/// # Panic
+	/// If the static vector cannot hold all values, this method would panic. \
+	/// Use `try_extend` method if you would like to handle the overflow.
+	fn extend<I:IntoIterator<Item=T>>(&mut self,iter:I)
+	{
+		for item in iter
+		{
+			self.push(item);
+		}
+	}
+}
+
+impl<'a,const N:usize,T:Copy+'a> Extend<&'a T> for StaticVec<N,T>
+{
+	/// Appends all values yielded by `iter` to the end of static vector.
+	/// 
+	/// # Panic
+	/// If the static vector cannot hold all values, this method would panic.
+	fn extend<I:IntoIterator<Item=&'a T>>(&mut self,iter:I)
+	{
+		for item in iter
+		{
+			self.push(*item);
+		}
+	}
+}
+
+impl<const N:usize,T> FromIterator<T> for StaticVec<N,T>
+{
+	/// Constructs a new static vector from the values yielded by `iter`.
+	/// 
+	/// # Panic
+	/// If `iter` yields more than `N` values, this method would panic. \
+	/// Use `try_from_iter` method if you would like to handle the overflow.
+	fn from_iter<I:IntoIterator<Item=T>>(iter:I)->Self
+	{
+		let mut v=Self::new();
+		v.extend(iter);
+		v
+	}
+}
+
+impl<const N:usize,T> IntoIterator for StaticVec<N,T>
+{
+	type Item = T;
+	type IntoIter = IntoIter<N,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		let v=ManuallyDrop::new(self);
+		IntoIter
+		{
+			// The `ManuallyDrop` wrapper guarantees the values will not be dropped twice.
+			buff:unsafe{ptr::read(&v.buff)},
+			index:0,
+			end:v.length
+		}
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a StaticVec<N,T>
+{
+	type Item = &'a T;
+	type IntoIter = slice::Iter<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a,const N:usize,T> IntoIterator for &'a mut StaticVec<N,T>
+{
+	type Item = &'a mut T;
+	type IntoIter = slice::IterMut<'a,T>;
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+/// The by-value iterator of `StaticVec`. \
+/// Values that are not yielded are dropped when the iterator is dropped.
+/// 
+/// # Example
+/// ```
+/// use static_collections::{vec_static,vec::StaticVec};
+/// let v:StaticVec<8,u64>=vec_static![1,2,3];
+/// let mut it=v.into_iter();
+/// assert_eq!(it.next(),Some(1));
+/// assert_eq!(it.next_back(),Some(3));
+/// assert_eq!(it.as_slice(),&[2]);
+/// ```
+pub struct IntoIter<const N:usize,T>
+{
+	buff:MaybeUninit<[T;N]>,
+	index:usize,
+	end:usize
+}
+
+impl<const N:usize,T> IntoIter<N,T>
+{
+	/// Returns the remaining values of this iterator as a slice.
+	pub fn as_slice(&self)->&[T]
+	{
+		unsafe
+		{
+			slice::from_raw_parts(self.buff.as_ptr().cast::<T>().add(self.index),self.end-self.index)
+		}
+	}
+
+	/// Returns the remaining values of this iterator as a mutable slice.
+	pub fn as_mut_slice(&mut self)->&mut [T]
+	{
+		unsafe
+		{
+			slice::from_raw_parts_mut(self.buff.as_mut_ptr().cast::<T>().add(self.index),self.end-self.index)
+		}
+	}
+}
+
+impl<const N:usize,T> Iterator for IntoIter<N,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.index<self.end
+		{
+			let v=unsafe{ptr::read(self.buff.as_ptr().cast::<T>().add(self.index))};
+			self.index+=1;
+			Some(v)
+		}
+		else
+		{
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		let l=self.end-self.index;
+		(l,Some(l))
+	}
+}
+
+impl<const N:usize,T> DoubleEndedIterator for IntoIter<N,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		if self.index<self.end
+		{
+			self.end-=1;
+			unsafe
+			{
+				Some(ptr::read(self.buff.as_ptr().cast::<T>().add(self.end)))
 			}
 		}
+		else
+		{
+			None
+		}
+	}
+}
+
+impl<const N:usize,T> ExactSizeIterator for IntoIter<N,T> {}
+impl<const N:usize,T> FusedIterator for IntoIter<N,T> {}
+
+impl<const N:usize,T> Drop for IntoIter<N,T>
+{
+	fn drop(&mut self)
+	{
+		// Force drop every remaining item.
+		unsafe
+		{
+			ptr::drop_in_place(self.as_mut_slice());
+		}
+	}
+}
+
+impl<const N:usize,T:fmt::Debug> fmt::Debug for IntoIter<N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+	}
+}
+
+/// The draining iterator of `StaticVec`, created by `StaticVec::drain` method. \
+/// Values that are not yielded are dropped when the iterator is dropped.
+pub struct Drain<'a,const N:usize,T>
+{
+	vec:&'a mut StaticVec<N,T>,
+	index:usize,
+	end:usize,
+	tail_start:usize,
+	tail_len:usize
+}
+
+impl<'a,const N:usize,T> Drain<'a,N,T>
+{
+	/// Returns the remaining values of this iterator as a slice.
+	pub fn as_slice(&self)->&[T]
+	{
+		unsafe
+		{
+			slice::from_raw_parts(self.vec.as_ptr().add(self.index),self.end-self.index)
+		}
+	}
+}
+
+impl<'a,const N:usize,T> Iterator for Drain<'a,N,T>
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.index<self.end
+		{
+			let v=unsafe{ptr::read(self.vec.as_ptr().add(self.index))};
+			self.index+=1;
+			Some(v)
+		}
+		else
+		{
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		let l=self.end-self.index;
+		(l,Some(l))
+	}
+}
+
+impl<'a,const N:usize,T> DoubleEndedIterator for Drain<'a,N,T>
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		if self.index<self.end
+		{
+			self.end-=1;
+			unsafe
+			{
+				Some(ptr::read(self.vec.as_ptr().add(self.end)))
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+}
+
+impl<'a,const N:usize,T> ExactSizeIterator for Drain<'a,N,T> {}
+impl<'a,const N:usize,T> FusedIterator for Drain<'a,N,T> {}
+
+impl<'a,const N:usize,T> Drop for Drain<'a,N,T>
+{
+	fn drop(&mut self)
+	{
+		// Move the tail back even if dropping the remaining values panics.
+		struct TailGuard<'b,'a,const N:usize,T>(&'b mut Drain<'a,N,T>);
+
+		impl<'b,'a,const N:usize,T> Drop for TailGuard<'b,'a,N,T>
+		{
+			fn drop(&mut self)
+			{
+				let d=&mut *self.0;
+				let start=d.vec.length;
+				unsafe
+				{
+					let p=d.vec.as_mut_ptr();
+					ptr::copy(p.add(d.tail_start),p.add(start),d.tail_len);
+				}
+				d.vec.length=start+d.tail_len;
+			}
+		}
+
+		let g=TailGuard(self);
+		let remaining:*mut [T]=ptr::slice_from_raw_parts_mut(unsafe{g.0.vec.as_mut_ptr().add(g.0.index)},g.0.end-g.0.index);
+		g.0.index=g.0.end;
+		// Force drop every remaining item.
+		unsafe
+		{
+			ptr::drop_in_place(remaining);
+		}
+	}
+}
+
+impl<'a,const N:usize,T:fmt::Debug> fmt::Debug for Drain<'a,N,T>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		f.debug_tuple("Drain").field(&self.as_slice()).finish()
+	}
+}
+
+// A static-vector is serialized as a sequence.
+#[cfg(feature = "serde")]
+impl<const N:usize,T:serde::Serialize> serde::Serialize for StaticVec<N,T>
+{
+	fn serialize<S:serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.collect_seq(self.iter())
+	}
+}
+
+// Deserializing a sequence longer than `N` fails with an `invalid_length` error.
+#[cfg(feature = "serde")]
+impl<'de,const N:usize,T:serde::Deserialize<'de>> serde::Deserialize<'de> for StaticVec<N,T>
+{
+	fn deserialize<D:serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+	{
+		deserializer.deserialize_seq(StaticVecVisitor(core::marker::PhantomData))
+	}
+}
+
+#[cfg(feature = "serde")]
+struct StaticVecVisitor<const N:usize,T>(core::marker::PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<'de,const N:usize,T:serde::Deserialize<'de>> serde::de::Visitor<'de> for StaticVecVisitor<N,T>
+{
+	type Value = StaticVec<N,T>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(formatter,"a sequence of at most {N} elements")
+	}
+
+	fn visit_seq<A:serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	{
+		let mut v=StaticVec::new();
+		while let Some(e)=seq.next_element()?
+		{
+			if v.try_push(e).is_err()
+			{
+				// The exact length is unknown, but there are at least `N+1` elements.
+				return Err(serde::de::Error::invalid_length(N+1,&self));
+			}
+		}
+		Ok(v)
+	}
+}
+
+/// The `vec_static!` macro helps building a static-vector easily,
+/// similar to the `vec!` macro in `std`/`alloc` crate.
+/// 
+/// The macro can be used in `const` and `static` initializers.
+/// 
+/// The length of a list and a literal repeat count are checked against the capacity at compile time. \
+/// Otherwise, the repeat count of `vec_static![elem;len]` may be a runtime value, and exceeding the capacity panics.
+/// 
+/// # Example
+/// ```
+/// use static_collections::{vec_static,vec::StaticVec};
+/// let a:StaticVec<12,u64>=vec_static![4;7];
+/// assert_eq!(a.as_slice(),&[4;7]);
+/// let n=a.len()-2;
+/// let c:StaticVec<12,u64>=vec_static![1;n];
+/// assert_eq!(c.as_slice(),&[1;5]);
+/// let d:StaticVec<12,u64>=vec_static![2;12];
+/// assert_eq!(d.len(),12);
+/// let b:StaticVec<16,u32>=vec_static![1,2,3,4,5,6,7,8];
+/// assert_eq!(b.as_slice(),&[1,2,3,4,5,6,7,8]);
+/// static TABLE:StaticVec<4,u16>=vec_static![0x10,0x20,0x30];
+/// assert_eq!(TABLE.as_slice(),&[0x10,0x20,0x30]);
+/// ```
+/// 
+/// Exceeding the capacity is rejected at compile time:
+/// ```compile_fail
+/// use static_collections::{vec_static,vec::StaticVec};
+/// let x:StaticVec<12,u64>=vec_static![1234;16];
+/// ```
+/// ```compile_fail
+/// use static_collections::{vec_static,vec::StaticVec};
+/// let x:StaticVec<2,u64>=vec_static![1,2,3];
+/// ```
+#[macro_export] macro_rules! vec_static
+{
+	()=>
+	(
+		$crate::vec::StaticVec::new()
+	);
+	($elem:expr;$len:literal)=>
+	(
+		{
+			let mut v=$crate::vec::StaticVec::new();
+			v._assert_capacity::<{$len}>();
+			let len:usize=$len;
+			let mut i:usize=0;
+			while i<len
+			{
+				let e=$elem;
+				unsafe
+				{
+					v.force_assign(i,e);
+				}
+				i+=1;
+			}
+			unsafe
+			{
+				v.force_resize(len);
+			}
+			v
+		}
+	);
+	($elem:expr;$len:expr)=>
+	(
+		{
+			let mut v=$crate::vec::StaticVec::new();
+			let len:usize=$len;
+			let mut i:usize=0;
+			while i<len
+			{
+				let e=$elem;
+				unsafe
+				{
+					v.force_assign(i,e);
+				}
+				i+=1;
+			}
+			unsafe
+			{
+				v.force_resize(len);
+			}
+			v
+		}
+	);
+	($($x:expr),+$(,)?)=>
+	(
+		$crate::vec::StaticVec::from_array([$($x),+])
+	);
+}
+
+#[cfg(test)] mod test
+{
+	extern crate std;
+
+	use core::sync::atomic::{AtomicUsize,Ordering};
+	use crate::{vec::StaticVec, xorshift};
+
+	#[should_panic]
+	#[test] fn vec_macro_overflow()
+	{
+		// A literal repeat count would be rejected at compile time, so the overflow is checked with a runtime count.
+		let n=16;
+		let _:StaticVec<12,u64>=vec_static![1234;n];
+	}
+
+	#[should_panic]
+	#[test] fn push_overflow()
+	{
+		let mut x:StaticVec<2,u64>=vec_static![1,2];
+		x.push(3);
+	}
+
+	#[test] fn clone()
+	{
+		let x:StaticVec<16,u64>=vec_static![1,2,3,4,5,6,7,8,9,10];
+		assert_eq!(x.len(),10);
+		let y=x.clone();
+		assert_eq!(*y,[1,2,3,4,5,6,7,8,9,10]);
+	}
+
+	#[test] fn compare()
+	{
+		let mut state:u64=0x7F4A_7C15_9E37_79B9;
+		for _ in 0..1000
+		{
+			let mut a:StaticVec<8,u8>=StaticVec::new();
+			let mut b:StaticVec<6,u8>=StaticVec::new();
+			for _ in 0..6
+			{
+				let r=xorshift(&mut state);
+				// Use a tiny alphabet so that equal prefixes are common.
+				match r%4
+				{
+					0=>a.push((r>>8) as u8%2),
+					1=>b.push((r>>8) as u8%2),
+					_=>{}
+				}
+			}
+			let (x,y)=(std::vec::Vec::from(a.as_slice()),std::vec::Vec::from(b.as_slice()));
+			assert_eq!(a==b,x==y);
+			assert_eq!(a.partial_cmp(&b),x.partial_cmp(&y));
+			assert_eq!(a.clone().cmp(&a),core::cmp::Ordering::Equal);
+			assert_eq!(a==*y.as_slice(),x==y);
+			let mut h:std::collections::HashSet<StaticVec<8,u8>>=std::collections::HashSet::new();
+			h.insert(a.clone());
+			assert!(h.contains(x.as_slice()));
+		}
+	}
+
+	struct DropCounter<'a>
+	{
+		counter:&'a AtomicUsize
+	}
+
+	impl<'a> Drop for DropCounter<'a>
+	{
+		fn drop(&mut self)
+		{
+			self.counter.fetch_add(1,Ordering::SeqCst);
+		}
+	}
 
+	#[test] fn drop()
+	{
 		let drop_count:AtomicUsize=AtomicUsize::new(0);
 		{
 			let mut v:StaticVec<8,DropCounter>=StaticVec::new();
@@ -462,4 +1701,122 @@ impl<const N:usize,T> DerefMut for StaticVec<N,T>
 		}
 		assert_eq!(drop_count.load(Ordering::SeqCst),7);
 	}
-}
\ No newline at end of file
+
+	#[derive(Debug)]
+	struct CloneBomb<'a>
+	{
+		fuse:usize,
+		counter:&'a AtomicUsize
+	}
+
+	impl<'a> Clone for CloneBomb<'a>
+	{
+		fn clone(&self) -> Self
+		{
+			assert!(self.fuse!=0,"Boom!");
+			Self{fuse:self.fuse,counter:self.counter}
+		}
+	}
+
+	impl<'a> Drop for CloneBomb<'a>
+	{
+		fn drop(&mut self)
+		{
+			self.counter.fetch_add(1,Ordering::SeqCst);
+		}
+	}
+
+	#[test] fn clone_panic()
+	{
+		let drop_count:AtomicUsize=AtomicUsize::new(0);
+		let mut v:StaticVec<8,CloneBomb>=StaticVec::new();
+		for fuse in [3,2,1,0,5]
+		{
+			v.push(CloneBomb{fuse,counter:&drop_count});
+		}
+		let r=std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| v.clone()));
+		assert!(r.is_err());
+		// The three values cloned before the panic are dropped.
+		assert_eq!(drop_count.load(Ordering::SeqCst),3);
+		core::mem::drop(v);
+		assert_eq!(drop_count.load(Ordering::SeqCst),8);
+	}
+
+	#[test] fn capacity_conversions()
+	{
+		let drop_count:AtomicUsize=AtomicUsize::new(0);
+		{
+			let mut v:StaticVec<16,DropCounter>=StaticVec::new();
+			for _ in 0..6
+			{
+				v.push(DropCounter{counter:&drop_count});
+			}
+			let v=v.try_into_capacity::<4>().err().unwrap().into_inner();
+			let mut v:StaticVec<6,DropCounter>=v.try_into_capacity().ok().unwrap();
+			let mut w:StaticVec<3,DropCounter>=v.split_off(3);
+			assert!(v.try_split_off::<2>(0).is_err());
+			assert_eq!((v.len(),w.len()),(3,3));
+			let mut x:StaticVec<5,DropCounter>=StaticVec::new();
+			x.append(&mut w);
+			assert!(x.try_append(&mut v).is_err());
+			assert!(w.is_empty());
+			let v:StaticVec<3,DropCounter>=v.try_into_capacity().ok().unwrap();
+			let a=v.into_array().ok().unwrap();
+			assert_eq!(drop_count.load(Ordering::SeqCst),0);
+			core::mem::drop(a);
+			assert_eq!(drop_count.load(Ordering::SeqCst),3);
+		}
+		assert_eq!(drop_count.load(Ordering::SeqCst),6);
+	}
+
+	#[test] fn into_iter_drop()
+	{
+		let drop_count:AtomicUsize=AtomicUsize::new(0);
+		{
+			let mut v:StaticVec<8,DropCounter>=StaticVec::new();
+			for _ in 0..5
+			{
+				v.push(DropCounter{counter:&drop_count});
+			}
+			let mut it=v.into_iter();
+			let x=it.next();
+			let y=it.next_back();
+			assert_eq!(drop_count.load(Ordering::SeqCst),0);
+			core::mem::drop(it);
+			assert_eq!(drop_count.load(Ordering::SeqCst),3);
+			core::mem::drop((x,y));
+		}
+		assert_eq!(drop_count.load(Ordering::SeqCst),5);
+	}
+
+	#[test] fn drain_drop()
+	{
+		let drop_count:AtomicUsize=AtomicUsize::new(0);
+		{
+			let mut v:StaticVec<8,(usize,DropCounter)>=StaticVec::new();
+			for i in 0..6
+			{
+				v.push((i,DropCounter{counter:&drop_count}));
+			}
+			let mut d=v.drain(1..4);
+			assert_eq!(d.next().map(|x| x.0),Some(1));
+			assert_eq!(drop_count.load(Ordering::SeqCst),1);
+			core::mem::drop(d);
+			assert_eq!(drop_count.load(Ordering::SeqCst),3);
+			assert_eq!(v.iter().map(|x| x.0).collect::<std::vec::Vec<usize>>(),[0,4,5]);
+			v.retain(|x| x.0!=4);
+			assert_eq!(drop_count.load(Ordering::SeqCst),4);
+			assert_eq!(v.len(),2);
+		}
+		assert_eq!(drop_count.load(Ordering::SeqCst),6);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test] fn serde()
+	{
+		use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+		let v:StaticVec<4,u16>=vec_static![1,2,3];
+		assert_tokens(&v,&[Token::Seq{len:Some(3)},Token::U16(1),Token::U16(2),Token::U16(3),Token::SeqEnd]);
+		assert_de_tokens_error::<StaticVec<2,u16>>(&[Token::Seq{len:Some(3)},Token::U16(1),Token::U16(2),Token::U16(3)],"invalid length 3, expected a sequence of at most 2 elements");
+	}
+}